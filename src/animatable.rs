@@ -0,0 +1,132 @@
+use crate::reexports::iced_core::{Color, Padding, Point, Rectangle, Size, Vector};
+use crate::{lerp, ColorSpace};
+
+/// A value that can be animated with a typed [`crate::Track`].
+///
+/// Implement this for your own types to animate them as a whole, instead of
+/// splitting them into `f32`s by hand.
+pub trait Animatable: Clone + std::fmt::Debug + Send + Sync + 'static {
+    /// Blend between `self` and `other`. Percent is 0.0 -> 1.0,
+    /// but may go past either end with springs and some eases.
+    #[must_use]
+    fn lerp(&self, other: &Self, percent: f32) -> Self;
+
+    /// How far apart two values are.
+    /// Keyframes that move at a [`crate::Speed`] take longer the further apart they are.
+    fn distance(&self, other: &Self) -> f32;
+}
+
+impl Animatable for f32 {
+    fn lerp(&self, other: &Self, percent: f32) -> Self {
+        lerp(*self, *other, percent)
+    }
+
+    fn distance(&self, other: &Self) -> f32 {
+        (other - self).abs()
+    }
+}
+
+impl Animatable for Point {
+    fn lerp(&self, other: &Self, percent: f32) -> Self {
+        Point::new(
+            lerp(self.x, other.x, percent),
+            lerp(self.y, other.y, percent),
+        )
+    }
+
+    fn distance(&self, other: &Self) -> f32 {
+        Point::distance(self, *other)
+    }
+}
+
+impl Animatable for Vector {
+    fn lerp(&self, other: &Self, percent: f32) -> Self {
+        Vector::new(
+            lerp(self.x, other.x, percent),
+            lerp(self.y, other.y, percent),
+        )
+    }
+
+    fn distance(&self, other: &Self) -> f32 {
+        (other.x - self.x).hypot(other.y - self.y)
+    }
+}
+
+impl Animatable for Size {
+    fn lerp(&self, other: &Self, percent: f32) -> Self {
+        Size::new(
+            lerp(self.width, other.width, percent),
+            lerp(self.height, other.height, percent),
+        )
+    }
+
+    fn distance(&self, other: &Self) -> f32 {
+        (other.width - self.width).hypot(other.height - self.height)
+    }
+}
+
+// Blended in the default `ColorSpace`. Each channel is 0.0 -> 1.0,
+// so the distance between black and white is about 1.7.
+impl Animatable for Color {
+    fn lerp(&self, other: &Self, percent: f32) -> Self {
+        ColorSpace::default().mix(*self, *other, percent)
+    }
+
+    fn distance(&self, other: &Self) -> f32 {
+        let [r, g, b, a] = [
+            other.r - self.r,
+            other.g - self.g,
+            other.b - self.b,
+            other.a - self.a,
+        ];
+        (r * r + g * g + b * b + a * a).sqrt()
+    }
+}
+
+impl Animatable for Padding {
+    fn lerp(&self, other: &Self, percent: f32) -> Self {
+        Padding {
+            top: lerp(self.top, other.top, percent),
+            right: lerp(self.right, other.right, percent),
+            bottom: lerp(self.bottom, other.bottom, percent),
+            left: lerp(self.left, other.left, percent),
+        }
+    }
+
+    // The side that moves the most.
+    fn distance(&self, other: &Self) -> f32 {
+        [
+            other.top - self.top,
+            other.right - self.right,
+            other.bottom - self.bottom,
+            other.left - self.left,
+        ]
+        .into_iter()
+        .fold(0., |max, side| side.abs().max(max))
+    }
+}
+
+impl Animatable for Rectangle {
+    fn lerp(&self, other: &Self, percent: f32) -> Self {
+        Rectangle::new(
+            Animatable::lerp(&self.position(), &other.position(), percent),
+            Animatable::lerp(&self.size(), &other.size(), percent),
+        )
+    }
+
+    // How far the corner that moves the most travels.
+    fn distance(&self, other: &Self) -> f32 {
+        let corners = |r: &Rectangle| {
+            [
+                Point::new(r.x, r.y),
+                Point::new(r.x + r.width, r.y),
+                Point::new(r.x, r.y + r.height),
+                Point::new(r.x + r.width, r.y + r.height),
+            ]
+        };
+        corners(self)
+            .into_iter()
+            .zip(corners(other))
+            .fold(0., |max, (one, two)| one.distance(two).max(max))
+    }
+}
@@ -0,0 +1,254 @@
+use std::f32::consts::{PI, TAU};
+
+use crate::lerp;
+use crate::reexports::iced_core::Color;
+
+/// The color space colors are blended in.
+///
+/// Blending in a perceptual space, like [`ColorSpace::Oklab`] or
+/// [`ColorSpace::Oklch`], keeps the colors in the middle of an animation
+/// from looking muddy or too dark.
+/// Set it for the whole [`crate::Timeline`], or for a single keyframe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorSpace {
+    /// Blend the red, green and blue channels in linear light.
+    #[default]
+    LinearRgb,
+    /// Blend the gamma encoded red, green and blue channels, like CSS does by default.
+    Srgb,
+    /// Blend the lightness, and the green-red and blue-yellow axes, in OKLab.
+    Oklab,
+    /// Blend lightness, chroma and hue in OKLCH. Hue takes the shortest way around.
+    Oklch,
+}
+
+impl ColorSpace {
+    /// Blend two colors. Percent is 0.0 -> 1.0,
+    /// where 0 is all of `one` and 1 is all of `two`.
+    #[must_use]
+    pub fn mix(self, one: Color, two: Color, percent: f32) -> Color {
+        if percent == 0. {
+            return one;
+        } else if percent == 1. {
+            return two;
+        }
+        let a = lerp(one.a, two.a, percent);
+        let mix = |one: [f32; 3], two: [f32; 3]| {
+            [
+                lerp(one[0], two[0], percent),
+                lerp(one[1], two[1], percent),
+                lerp(one[2], two[2], percent),
+            ]
+        };
+        let [r, g, b] = match self {
+            ColorSpace::Srgb => mix([one.r, one.g, one.b], [two.r, two.g, two.b]),
+            ColorSpace::LinearRgb => to_srgb(mix(to_linear(one), to_linear(two))),
+            ColorSpace::Oklab => to_srgb(from_oklab(mix(
+                to_oklab(to_linear(one)),
+                to_oklab(to_linear(two)),
+            ))),
+            ColorSpace::Oklch => {
+                let [l1, c1, h1] = to_oklch(to_oklab(to_linear(one)));
+                let [l2, c2, h2] = to_oklch(to_oklab(to_linear(two)));
+                // Greys have no hue, so keep the hue of the other color.
+                let (h1, h2) = match (c1 < ACHROMATIC, c2 < ACHROMATIC) {
+                    (true, false) => (h2, h2),
+                    (false, true) => (h1, h1),
+                    _ => (h1, h2),
+                };
+                let mut delta = (h2 - h1) % TAU;
+                if delta > PI {
+                    delta -= TAU;
+                } else if delta < -PI {
+                    delta += TAU;
+                }
+                let lch = [
+                    lerp(l1, l2, percent),
+                    lerp(c1, c2, percent),
+                    h1 + delta * percent,
+                ];
+                to_srgb(from_oklab(from_oklch(lch)))
+            }
+        };
+        Color::from_rgba(
+            r.clamp(0., 1.),
+            g.clamp(0., 1.),
+            b.clamp(0., 1.),
+            a.clamp(0., 1.),
+        )
+    }
+
+    // Stored on the timeline as a step, so keyframes can set it.
+    pub(crate) fn as_f32(self) -> f32 {
+        match self {
+            ColorSpace::LinearRgb => 0.,
+            ColorSpace::Srgb => 1.,
+            ColorSpace::Oklab => 2.,
+            ColorSpace::Oklch => 3.,
+        }
+    }
+
+    pub(crate) fn from_f32(value: f32) -> Option<Self> {
+        match value.round() as i32 {
+            0 => Some(ColorSpace::LinearRgb),
+            1 => Some(ColorSpace::Srgb),
+            2 => Some(ColorSpace::Oklab),
+            3 => Some(ColorSpace::Oklch),
+            _ => None,
+        }
+    }
+}
+
+// Below this chroma a color is treated as grey.
+const ACHROMATIC: f32 = 1e-4;
+
+fn to_linear(color: Color) -> [f32; 3] {
+    let [r, g, b, _a] = color.into_linear();
+    [r, g, b]
+}
+
+fn to_srgb(linear: [f32; 3]) -> [f32; 3] {
+    linear.map(|c| {
+        if c <= 0.003_130_8 {
+            c * 12.92
+        } else {
+            1.055 * c.powf(1. / 2.4) - 0.055
+        }
+    })
+}
+
+// https://bottosson.github.io/posts/oklab/
+fn to_oklab([r, g, b]: [f32; 3]) -> [f32; 3] {
+    let l = (0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b).cbrt();
+    let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+    let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+    [
+        0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+        1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+        0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+    ]
+}
+
+fn from_oklab([l, a, b]: [f32; 3]) -> [f32; 3] {
+    let l_ = (l + 0.396_337_78 * a + 0.215_803_76 * b).powi(3);
+    let m_ = (l - 0.105_561_346 * a - 0.063_854_17 * b).powi(3);
+    let s_ = (l - 0.089_484_18 * a - 1.291_485_5 * b).powi(3);
+    [
+        4.076_741_7 * l_ - 3.307_711_6 * m_ + 0.230_969_94 * s_,
+        -1.268_438 * l_ + 2.609_757_4 * m_ - 0.341_319_38 * s_,
+        -0.004_196_086_3 * l_ - 0.703_418_6 * m_ + 1.707_614_7 * s_,
+    ]
+}
+
+fn to_oklch([l, a, b]: [f32; 3]) -> [f32; 3] {
+    [l, a.hypot(b), b.atan2(a)]
+}
+
+fn from_oklch([l, c, h]: [f32; 3]) -> [f32; 3] {
+    [l, c * h.cos(), c * h.sin()]
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::keyframes::{get_appearance, get_color_space, StyleContainer};
+    use crate::reexports::iced_core::Background;
+    use crate::{chain, id, style_container, Duration, Instant, Speed, Timeline};
+
+    fn round(val: f32) -> f32 {
+        (val * 10E+5).round() / 10E+5
+    }
+
+    #[test]
+    fn color_space() {
+        let (black, white) = (Color::BLACK, Color::WHITE);
+        assert_eq!(0.5, ColorSpace::Srgb.mix(black, white, 0.5).r);
+        assert_eq!(
+            0.735357,
+            round(ColorSpace::LinearRgb.mix(black, white, 0.5).r)
+        );
+        assert_eq!(white, ColorSpace::Oklab.mix(black, white, 1.));
+
+        // Red to blue takes the short way around, through magenta, not green.
+        let red = Color::from_rgb(1., 0., 0.);
+        let blue = Color::from_rgb(0., 0., 1.);
+        let mid = ColorSpace::Oklch.mix(red, blue, 0.5);
+        assert!(mid.r > 0.5 && mid.b > 0.5 && mid.g < 0.1);
+        // White has no hue, so red keeps its own and only loses chroma,
+        // which is a straight line in OKLab.
+        let pink = ColorSpace::Oklch.mix(red, white, 0.5);
+        let straight = ColorSpace::Oklab.mix(red, white, 0.5);
+        assert!((pink.g - straight.g).abs() < 1e-4 && (pink.b - straight.b).abs() < 1e-4);
+
+        let id = id::StyleContainer::unique();
+        let iced_id = id.clone().into();
+        let start = Instant::now();
+        let mut timeline = Timeline::new();
+        let _ = timeline.set_color_space(ColorSpace::Oklch);
+        let _ = timeline.set_chain(chain![
+            id.clone(),
+            style_container(Duration::ZERO).background(red),
+            style_container(Duration::from_secs(1)).background(blue),
+        ]);
+        timeline.start_at(start);
+        timeline.now(start + Duration::from_millis(500));
+        let space = get_color_space(&iced_id, &timeline, StyleContainer::COLOR_SPACE);
+        assert_eq!(ColorSpace::Oklch, space);
+        let appearance = get_appearance(
+            &iced_id,
+            &timeline,
+            StyleContainer::BACKGROUND_R,
+            StyleContainer::BACKGROUND_SET,
+            StyleContainer::BACKGROUND_ANGLE,
+            space,
+        );
+        assert_eq!(Some(Background::Color(mid)), appearance.background);
+
+        // A keyframe's color space wins over the timeline's.
+        let _ = timeline.set_chain(chain![
+            id,
+            style_container(Duration::ZERO).background(red),
+            style_container(Duration::from_secs(1))
+                .background(blue)
+                .color_space(ColorSpace::Srgb),
+        ]);
+        timeline.start_at(start);
+        timeline.now(start + Duration::from_millis(500));
+        let space = get_color_space(&iced_id, &timeline, StyleContainer::COLOR_SPACE);
+        assert_eq!(ColorSpace::Srgb, space);
+        let appearance = get_appearance(
+            &iced_id,
+            &timeline,
+            StyleContainer::BACKGROUND_R,
+            StyleContainer::BACKGROUND_SET,
+            StyleContainer::BACKGROUND_ANGLE,
+            space,
+        );
+        assert_eq!(
+            Some(Background::Color(Color::from_rgb(0.5, 0., 0.5))),
+            appearance.background
+        );
+
+        // The color space is never blended, and has no distance to travel at a
+        // speed, so it switches straight away.
+        let _ = timeline.set_chain(chain![
+            id,
+            style_container(Duration::ZERO)
+                .background(red)
+                .color_space(ColorSpace::Srgb),
+            style_container(Speed::per_secs(1.))
+                .background(blue)
+                .color_space(ColorSpace::Oklch),
+        ]);
+        timeline.start_at(start);
+        assert_eq!(Some(Duration::from_secs(1)), timeline.remaining(&iced_id));
+        timeline.now(start + Duration::from_millis(250));
+        assert_eq!(
+            ColorSpace::Oklch.as_f32(),
+            timeline
+                .get(&iced_id, StyleContainer::COLOR_SPACE)
+                .unwrap()
+                .value
+        );
+    }
+}
@@ -0,0 +1,318 @@
+use std::sync::Arc;
+
+use crate::reexports::iced_core::widget;
+use crate::timeline::{Chain, IntoChains, Stagger};
+use crate::Duration;
+
+/// Play animations one after another, as a single [`Group`].
+/// Each item is a chain, a [`Stagger`], or another group.
+/// ```ignore
+/// let dialog = widget::Id::new("dialog");
+/// self.timeline
+///     .set_chain(sequence![dialog, backdrop, panel, parallel![content_id, title, body]])
+///     .start();
+/// ```
+#[macro_export]
+macro_rules! sequence {
+    ($id:expr $(, $member:expr)* $(,)?) => {
+        $crate::Group::sequence($id)$(.add($member))*
+    };
+}
+
+/// Play animations at the same time, as a single [`Group`].
+/// Each item is a chain, a [`Stagger`], or another group.
+#[macro_export]
+macro_rules! parallel {
+    ($id:expr $(, $member:expr)* $(,)?) => {
+        $crate::Group::parallel($id)$(.add($member))*
+    };
+}
+
+/// Animations on many widgets, choreographed as one unit.
+///
+/// The group has an Id of its own. Pass it to [`crate::Timeline`] to pause,
+/// resume, seek or change the speed of all its animations at once, and to
+/// query if the group is running. [`crate::AnimationEvent::Completed`] is
+/// emitted with the group's Id once all of them have completed.
+///
+/// Members start after the previous one ends in a sequence, or all at the start
+/// of a parallel group. They can be moved with an offset, or started at a label.
+/// Members that have not started yet hold their first keyframe.
+/// ```ignore
+/// let dialog = Group::sequence(widget::Id::new("dialog"))
+///     .add(backdrop)
+///     .label("open")
+///     // Start sliding before the backdrop is done fading in.
+///     .add_before(Duration::from_millis(100), panel)
+///     .add_at("open", Stagger::new(rows, row_template).delay(Duration::from_millis(50)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Group {
+    id: widget::Id,
+    // Can't be controlled by its Id, like the members of a stagger.
+    anonymous: bool,
+    sequence: bool,
+    entries: Vec<Entry>,
+}
+
+#[derive(Debug, Clone)]
+enum Entry {
+    Label(String),
+    Member(Position, Member),
+}
+
+/// Anything that can be added to a [`Group`].
+#[derive(Debug, Clone)]
+pub enum Member {
+    /// A single animation chain.
+    Chain(Chain),
+    /// A group of animations, played as one unit.
+    Group(Group),
+}
+
+impl<T: Into<Chain>> From<T> for Member {
+    fn from(chain: T) -> Self {
+        Member::Chain(chain.into())
+    }
+}
+
+impl From<Group> for Member {
+    fn from(group: Group) -> Self {
+        Member::Group(group)
+    }
+}
+
+// Each Id of a stagger is a member of a parallel group, that has no Id of its own.
+impl From<Stagger> for Member {
+    fn from(stagger: Stagger) -> Self {
+        let mut group = Group::parallel(widget::Id::unique());
+        group.anonymous = true;
+        Member::Group(stagger.into_chains().into_iter().fold(group, Group::add))
+    }
+}
+
+/// Where a member of a [`Group`] starts. Relative to where it would start
+/// without one, or to a label.
+#[derive(Debug, Clone, Default)]
+struct Position {
+    label: Option<String>,
+    after: Duration,
+    before: Duration,
+}
+
+impl Group {
+    /// Create a group that plays its members one after another.
+    pub fn sequence(id: impl Into<widget::Id>) -> Self {
+        Group {
+            id: id.into(),
+            anonymous: false,
+            sequence: true,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Create a group that plays its members at the same time.
+    pub fn parallel(id: impl Into<widget::Id>) -> Self {
+        Group {
+            id: id.into(),
+            anonymous: false,
+            sequence: false,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Add a member. In a sequence it starts once the previous member has ended,
+    /// in a parallel group it starts with the group.
+    #[must_use]
+    #[allow(clippy::should_implement_trait)]
+    pub fn add(self, member: impl Into<Member>) -> Self {
+        self.add_with(Position::default(), member)
+    }
+
+    /// Add a member that starts `offset` later than it would with `add`.
+    #[must_use]
+    pub fn add_after(self, offset: Duration, member: impl Into<Member>) -> Self {
+        self.add_with(
+            Position {
+                after: offset,
+                ..Position::default()
+            },
+            member,
+        )
+    }
+
+    /// Add a member that starts `offset` earlier than it would with `add`,
+    /// overlapping the end of the previous member. Never earlier than the group.
+    #[must_use]
+    pub fn add_before(self, offset: Duration, member: impl Into<Member>) -> Self {
+        self.add_with(
+            Position {
+                before: offset,
+                ..Position::default()
+            },
+            member,
+        )
+    }
+
+    /// Add a member that starts at a label. If there is no such label,
+    /// it starts as it would with `add`.
+    #[must_use]
+    pub fn add_at(self, label: impl Into<String>, member: impl Into<Member>) -> Self {
+        self.add_with(
+            Position {
+                label: Some(label.into()),
+                ..Position::default()
+            },
+            member,
+        )
+    }
+
+    /// Mark where the next member would start, to start other members there
+    /// with `add_at`. Labels only apply to the group they are in.
+    #[must_use]
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.entries.push(Entry::Label(label.into()));
+        self
+    }
+
+    fn add_with(mut self, position: Position, member: impl Into<Member>) -> Self {
+        self.entries.push(Entry::Member(position, member.into()));
+        self
+    }
+
+    // Split the group into its chains, and the layout of their Ids.
+    fn split(self, chains: &mut Vec<Chain>) -> Layout {
+        let entries = self
+            .entries
+            .into_iter()
+            .map(|entry| match entry {
+                Entry::Label(label) => LayoutEntry::Label(label),
+                Entry::Member(position, Member::Chain(chain)) => {
+                    let id = chain.id.clone();
+                    chains.push(chain);
+                    LayoutEntry::Member(position, Node::Chain(id))
+                }
+                Entry::Member(position, Member::Group(group)) => {
+                    LayoutEntry::Member(position, Node::Group(group.split(chains)))
+                }
+            })
+            .collect();
+        Layout {
+            id: (!self.anonymous).then_some(self.id),
+            sequence: self.sequence,
+            entries,
+        }
+    }
+}
+
+impl IntoChains for Group {
+    fn into_chains(self) -> Vec<Chain> {
+        let mut chains = Vec::new();
+        let layout = Arc::new(self.split(&mut chains));
+        chains
+            .into_iter()
+            .map(|chain| chain.in_group(layout.clone()))
+            .collect()
+    }
+}
+
+/// The Ids of a [`Group`]'s members, and where they start.
+/// The [`crate::Timeline`] schedules the group with it once each member's
+/// length is known.
+#[derive(Debug)]
+pub(crate) struct Layout {
+    pub(crate) id: Option<widget::Id>,
+    sequence: bool,
+    entries: Vec<LayoutEntry>,
+}
+
+#[derive(Debug)]
+enum LayoutEntry {
+    Label(String),
+    Member(Position, Node),
+}
+
+#[derive(Debug)]
+enum Node {
+    Chain(widget::Id),
+    Group(Layout),
+}
+
+impl Layout {
+    /// The Ids of all chains in the group, including those of nested groups.
+    pub(crate) fn chains(&self) -> Vec<widget::Id> {
+        self.entries
+            .iter()
+            .flat_map(|entry| match entry {
+                LayoutEntry::Member(_, Node::Chain(id)) => vec![id.clone()],
+                LayoutEntry::Member(_, Node::Group(group)) => group.chains(),
+                LayoutEntry::Label(_) => Vec::new(),
+            })
+            .collect()
+    }
+
+    /// This group, and all groups nested in it, that have an Id.
+    pub(crate) fn groups(&self) -> Vec<(&widget::Id, &Layout)> {
+        let nested = self.entries.iter().flat_map(|entry| match entry {
+            LayoutEntry::Member(_, Node::Group(group)) => group.groups(),
+            _ => Vec::new(),
+        });
+        self.id.iter().map(|id| (id, self)).chain(nested).collect()
+    }
+
+    /// Work out when each chain and group starts, from the start of the outermost
+    /// group, given how long each chain plays for. Returns when this group ends.
+    pub(crate) fn schedule(
+        &self,
+        start: Duration,
+        length: &impl Fn(&widget::Id) -> Duration,
+        schedule: &mut Schedule,
+    ) -> Duration {
+        let mut labels: Vec<(&str, Duration)> = Vec::new();
+        // Where the next member starts, and when the group ends.
+        let mut cursor = start;
+        let mut end = start;
+        for entry in &self.entries {
+            let (position, node) = match entry {
+                LayoutEntry::Label(label) => {
+                    labels.push((label, cursor));
+                    continue;
+                }
+                LayoutEntry::Member(position, node) => (position, node),
+            };
+            let at = position
+                .label
+                .as_deref()
+                .and_then(|label| labels.iter().rev().find(|(l, _)| *l == label))
+                .map_or(cursor, |(_, at)| *at);
+            let at = (at + position.after)
+                .saturating_sub(position.before)
+                .max(start);
+            let member_end = match node {
+                Node::Chain(id) => {
+                    schedule.chains.push((id.clone(), at));
+                    at + length(id)
+                }
+                Node::Group(group) => group.schedule(at, length, schedule),
+            };
+            if self.sequence {
+                cursor = member_end;
+            }
+            end = end.max(member_end);
+        }
+        if let Some(id) = &self.id {
+            schedule.groups.push((id.clone(), start, end));
+        }
+        end
+    }
+}
+
+/// When the chains and groups of a [`Group`] start, from the start of the
+/// outermost group.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Schedule {
+    /// When each chain starts.
+    pub(crate) chains: Vec<(widget::Id, Duration)>,
+    /// When each group starts and ends.
+    pub(crate) groups: Vec<(widget::Id, Duration, Duration)>,
+}
@@ -9,8 +9,15 @@ mod space;
 mod style_button;
 mod style_container;
 mod toggler;
+mod transform;
 
-use crate::reexports::iced_core::{widget, Length};
+use crate::reexports::iced_core::{
+    gradient::{ColorStop, Linear},
+    widget, Background, Color, Element, Gradient, Length, Padding, Radians,
+    Renderer as IcedRenderer,
+};
+use crate::utils::static_array_from_iter;
+use crate::widget::{blend_backgrounds, AppearanceOverride, LengthType, Resize};
 
 pub use button::Button;
 #[cfg(feature = "libcosmic")]
@@ -22,15 +29,17 @@ pub use helpers::cards;
 pub use helpers::id;
 pub use helpers::lazy;
 pub use helpers::{
-    button, chain, column, container, row, space, style_button, style_container, toggler,
+    button, chain, column, container, row, space, style_button, style_container, toggler, transform,
 };
 pub use row::Row;
 pub use space::Space;
 pub use style_button::StyleButton;
 pub use style_container::StyleContainer;
 pub use toggler::Toggler;
+pub use transform::Transform;
 
-use crate::Timeline;
+use crate::timeline::{Frame, Interped, PropertyKey};
+use crate::{ColorSpace, Ease, MovementType, Timeline};
 
 /// The macro used to cleanly and efficently build an animation chain.
 /// Works for ann Id's that implement `into_chain` and `into_chain_with_children`
@@ -52,26 +61,693 @@ macro_rules! anim{
   };
 }
 
+/// How many times an animation chain plays.
 #[derive(Debug, Copy, Clone, PartialEq, Default)]
 pub enum Repeat {
+    /// Play the animation once.
     #[default]
     Never,
+    /// Play the animation over and over, forever.
     Forever,
+    /// Play the animation this many times in total.
+    Times(u32),
+    /// Play the animation forwards, then backwards, forever.
+    PingPong,
+}
+
+impl Repeat {
+    /// Does the animation never end?
+    #[must_use]
+    pub fn is_infinite(&self) -> bool {
+        matches!(self, Repeat::Forever | Repeat::PingPong)
+    }
+}
+
+/// Overrides the keyframe's `at` and `ease` for a single property.
+#[derive(Debug, Copy, Clone, Default)]
+pub(crate) struct Timing {
+    at: Option<MovementType>,
+    ease: Option<Ease>,
+}
+
+impl Timing {
+    pub(crate) fn eager(self, at: MovementType, value: f32, ease: Ease) -> Frame {
+        Frame::eager(self.at.unwrap_or(at), value, self.ease.unwrap_or(ease))
+    }
+
+    pub(crate) fn lazy(self, at: MovementType, default: f32, ease: Ease) -> Frame {
+        Frame::lazy(self.at.unwrap_or(at), default, self.ease.unwrap_or(ease))
+    }
+
+    pub(crate) fn step(self, at: MovementType, value: f32, ease: Ease) -> Frame {
+        Frame::step(self.at.unwrap_or(at), value, self.ease.unwrap_or(ease))
+    }
+
+    pub(crate) fn lazy_step(self, at: MovementType, default: f32, ease: Ease) -> Frame {
+        Frame::lazy_step(self.at.unwrap_or(at), default, self.ease.unwrap_or(ease))
+    }
+}
+
+/// Generates the per-property `at` and `ease` overrides for a keyframe.
+/// Each property needs a [`Timing`] field to store the override.
+macro_rules! timing_overrides {
+    ($($timing:ident: $at:ident, $ease:ident;)+) => {
+        $(
+            /// Animate this property over a different time than the rest of the keyframe.
+            pub fn $at(mut self, at: impl Into<$crate::MovementType>) -> Self {
+                self.$timing.at = Some(at.into());
+                self
+            }
+
+            /// Animate this property with a different ease than the rest of the keyframe.
+            pub fn $ease<E: Into<$crate::Ease>>(mut self, ease: E) -> Self {
+                self.$timing.ease = Some(ease.into());
+                self
+            }
+        )+
+    };
+}
+pub(crate) use timing_overrides;
+
+/// Declares the properties of a keyframe, in the order of its frames.
+///
+/// Each property gets a typed [`crate::Property`] key on the keyframe, to read it
+/// back with [`crate::Timeline::get`], and the frames for an eager and a lazy
+/// keyframe. Those build the keyframe's `From<Keyframe> for Vec<Option<Frame>>`,
+/// so the keys and the frames can never get out of step.
+/// The keyframe needs an `is_eager: bool` field.
+///
+/// A property can span several frames, written `NAME[len] => ...`. Its key is
+/// the first of them, and its eager and lazy frames are any iterator of `len` frames.
+/// ```ignore
+/// properties! {
+///     Circle(circle) {
+///         /// The radius of the circle.
+///         RADIUS =>
+///             circle.radius.map(|r| Frame::eager(circle.at, r, circle.ease)),
+///             Some(Frame::lazy(circle.at, 0., circle.ease));
+///     }
+/// }
+/// // In `view()`
+/// let radius = timeline.get(&id, Circle::RADIUS).map_or(0., |m| m.value);
+/// ```
+#[macro_export]
+macro_rules! properties {
+    ($keyframe:ident($k:ident) { $($(#[$meta:meta])* $name:ident $([$len:expr])? => $eager:expr, $lazy:expr;)+ }) => {
+        $crate::properties!(@keys $keyframe, 0; $($(#[$meta])* $name $([$len])?;)+);
+
+        impl From<$keyframe> for Vec<Option<$crate::timeline::Frame>> {
+            fn from($k: $keyframe) -> Vec<Option<$crate::timeline::Frame>> {
+                let mut frames = Vec::new();
+                if $k.is_eager {
+                    $(frames.extend($crate::properties!(@frames $([$len])? $eager));)+
+                } else {
+                    $(frames.extend($crate::properties!(@frames $([$len])? $lazy));)+
+                }
+                frames
+            }
+        }
+    };
+    (@keys $keyframe:ident, $index:expr; $(#[$meta:meta])* $name:ident $([$len:expr])?; $($rest:tt)*) => {
+        impl $keyframe {
+            $(#[$meta])*
+            pub const $name: $crate::timeline::Property<$keyframe> =
+                $crate::timeline::Property::new($index);
+        }
+        $crate::properties!(@keys $keyframe, $index + $crate::properties!(@len $([$len])?); $($rest)*);
+    };
+    (@keys $keyframe:ident, $index:expr;) => {};
+    (@len [$len:expr]) => {
+        $len
+    };
+    (@len) => {
+        1
+    };
+    (@frames [$len:expr] $frames:expr) => {
+        $frames
+    };
+    (@frames $frame:expr) => {
+        [$frame]
+    };
+}
+
+/// Generates the builder methods of an animation chain: `link`, `hold`,
+/// `delay`, the loops and `reverse`. Shared by the chains of [`crate::keyframe!`]
+/// and [`crate::Track`], which convert with [`crate::Chain::build`].
+///
+/// The chain needs `holds: Vec<(usize, Duration)>`, `delay: Duration`,
+/// `repeat: Repeat` and `reverse: bool` fields, and a `Vec` of its links.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! chain_builder {
+    ($links:ident: $link:ty) => {
+        /// Link another keyframe.
+        #[must_use]
+        pub fn link(mut self, keyframe: $link) -> Self {
+            self.$links.push(keyframe);
+            self
+        }
+
+        /// Hold every property at its current value for `duration`,
+        /// before animating to the next keyframe.
+        /// A hold before the first keyframe delays the chain.
+        #[must_use]
+        pub fn hold(mut self, duration: $crate::Duration) -> Self {
+            if self.$links.is_empty() {
+                self.delay += duration;
+            } else {
+                self.holds.push((self.$links.len(), duration));
+            }
+            self
+        }
+
+        /// Wait `delay` before playing the animation. Delays add up, with each
+        /// other and with holds before the first keyframe.
+        /// The delay is played again each time the animation loops.
+        #[must_use]
+        pub fn delay(mut self, delay: $crate::Duration) -> Self {
+            self.delay += delay;
+            self
+        }
+
+        /// Sets the animation to loop forever.
+        #[must_use]
+        pub fn loop_forever(mut self) -> Self {
+            self.repeat = $crate::Repeat::Forever;
+            self
+        }
+
+        /// Sets the animation to only loop once.
+        /// This is the default, and only useful to
+        /// stop an animation that was previously set
+        /// to loop forever.
+        #[must_use]
+        pub fn loop_once(mut self) -> Self {
+            self.repeat = $crate::Repeat::Never;
+            self
+        }
+
+        /// Sets the animation to play this many times.
+        #[must_use]
+        pub fn loop_times(mut self, times: u32) -> Self {
+            self.repeat = $crate::Repeat::Times(times);
+            self
+        }
+
+        /// Sets the animation to play forwards, then backwards, forever.
+        #[must_use]
+        pub fn ping_pong(mut self) -> Self {
+            self.repeat = $crate::Repeat::PingPong;
+            self
+        }
+
+        /// Plays the animation backwards, from the last keyframe to the first.
+        #[must_use]
+        pub fn reverse(mut self) -> Self {
+            self.reverse = !self.reverse;
+            self
+        }
+    };
+}
+
+/// Generates the boilerplate of an animatable keyframe, from its struct.
+///
+/// Adds the `at`, `ease` and `is_eager` fields to the struct, with `new`, `lazy`
+/// and `ease` for it. Also generates the keyframe's animation `Id` and `Chain`,
+/// with everything the [`crate::chain!`] macro needs, and the chain's
+/// conversion to a [`crate::Chain`]. Use it in its own module, as every keyframe
+/// gets an `Id` and a `Chain` of its own.
+///
+/// Fields start out as their `Default`, unless they are given a value.
+/// Pair it with [`crate::properties!`] for the frames, and write `as_widget` for
+/// your widget by hand.
+/// ```ignore
+/// keyframe! {
+///     #[derive(Debug, Clone, Copy)]
+///     pub struct Circle {
+///         radius: Option<f32>,
+///         thickness: f32 = 1.,
+///     }
+/// }
+///
+/// impl Circle {
+///     pub fn radius(mut self, radius: f32) -> Self {
+///         self.radius = Some(radius);
+///         self
+///     }
+/// }
+///
+/// properties! {
+///     Circle(circle) {
+///         /// The radius of the circle.
+///         RADIUS =>
+///             circle.radius.map(|r| Frame::eager(circle.at, r, circle.ease)),
+///             Some(Frame::lazy(circle.at, 0., circle.ease));
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! keyframe {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $keyframe:ident {
+            $($(#[$field_meta:meta])* $field:ident: $ty:ty $(= $default:expr)?),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[must_use = "Keyframes are intended to be used in an animation chain."]
+        $vis struct $keyframe {
+            at: $crate::MovementType,
+            ease: $crate::Ease,
+            is_eager: bool,
+            $($(#[$field_meta])* $field: $ty,)*
+        }
+
+        impl $keyframe {
+            /// Create a keyframe, that animates to its values by `at`.
+            pub fn new(at: impl Into<$crate::MovementType>) -> Self {
+                $keyframe {
+                    at: at.into(),
+                    ease: $crate::Linear::InOut.into(),
+                    is_eager: true,
+                    $($field: $crate::keyframe!(@default $($default)?),)*
+                }
+            }
+
+            /// Create a keyframe, that continues from wherever
+            /// the animation is when it starts.
+            pub fn lazy(at: impl Into<$crate::MovementType>) -> Self {
+                $keyframe {
+                    at: at.into(),
+                    ease: $crate::Linear::InOut.into(),
+                    is_eager: false,
+                    $($field: $crate::keyframe!(@default $($default)?),)*
+                }
+            }
+
+            /// The ease into this keyframe.
+            pub fn ease<E: Into<$crate::Ease>>(mut self, ease: E) -> Self {
+                self.ease = ease.into();
+                self
+            }
+        }
+
+        #[doc = concat!(
+            "A ", stringify!($keyframe), "'s animation Id. Used for linking animation ",
+            "built in `update()` with widget output in `view()`"
+        )]
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        $vis struct Id($crate::reexports::iced_core::widget::Id);
+
+        impl Id {
+            /// Creates a custom [`Id`].
+            pub fn new(id: impl Into<std::borrow::Cow<'static, str>>) -> Self {
+                Self($crate::reexports::iced_core::widget::Id::new(id))
+            }
+
+            /// Creates a unique [`Id`].
+            ///
+            /// This function produces a different [`Id`] every time it is called.
+            #[must_use]
+            pub fn unique() -> Self {
+                Self($crate::reexports::iced_core::widget::Id::unique())
+            }
+
+            /// Used by [`crate::chain!`] macro
+            #[must_use]
+            pub fn into_chain(self) -> Chain {
+                Chain::new(self)
+            }
+
+            /// Used by [`crate::chain!`] macro
+            #[must_use]
+            pub fn into_chain_with_children(self, children: Vec<$keyframe>) -> Chain {
+                Chain::with_children(self, children)
+            }
+        }
+
+        impl From<Id> for $crate::reexports::iced_core::widget::Id {
+            fn from(id: Id) -> Self {
+                id.0
+            }
+        }
+
+        /// An animation, where each keyframe is "chained" together.
+        #[derive(Debug, Clone)]
+        $vis struct Chain {
+            id: Id,
+            links: Vec<$keyframe>,
+            // How long to hold, after how many links.
+            holds: Vec<(usize, $crate::Duration)>,
+            delay: $crate::Duration,
+            repeat: $crate::Repeat,
+            reverse: bool,
+        }
+
+        impl Chain {
+            /// Create a new, empty animation chain.
+            /// You probably want to use the [`crate::chain!`] macro instead.
+            #[must_use]
+            pub fn new(id: Id) -> Self {
+                Self::with_children(id, Vec::new())
+            }
+
+            /// Create a chain pre-filled with children.
+            /// You probably want to use the [`crate::chain!`] macro instead.
+            #[must_use]
+            pub fn with_children(id: Id, children: Vec<$keyframe>) -> Self {
+                Chain {
+                    id,
+                    links: children,
+                    holds: Vec::new(),
+                    delay: $crate::Duration::ZERO,
+                    repeat: $crate::Repeat::Never,
+                    reverse: false,
+                }
+            }
+
+            $crate::chain_builder!(links: $keyframe);
+        }
+
+        impl From<Chain> for $crate::Chain {
+            fn from(chain: Chain) -> Self {
+                $crate::Chain::new(chain.id.into(), chain.repeat, Vec::new()).build(
+                    chain.links,
+                    chain.holds,
+                    chain.delay,
+                    chain.reverse,
+                )
+            }
+        }
+    };
+    (@default) => {
+        Default::default()
+    };
+    (@default $default:expr) => {
+        $default
+    };
 }
 
 pub trait IsChain {
     fn repeat(&self) -> Repeat;
 }
 
-pub fn get_length(id: &widget::Id, timeline: &Timeline, index: usize, default: Length) -> Length {
+/// Get an animated length, from its `pixels` and its `kind`. Blends between
+/// lengths that are not `Fixed` are left for the widget to resolve at layout time.
+pub fn get_length(
+    id: &widget::Id,
+    timeline: &Timeline,
+    pixels: impl PropertyKey,
+    kind: impl PropertyKey,
+    default: Length,
+) -> LengthType {
+    let Some(kind) = timeline.get(id, kind) else {
+        return LengthType::Static(default);
+    };
+    // Only `Fixed` lengths set the pixels, so the pixels of a link between a
+    // `Fixed` length and another kind are the `Fixed` end of the link.
+    let pixels = timeline.get(id, pixels);
+    let previous = as_length(kind.previous, pixels.map_or(0., |m| m.previous));
+    let next = as_length(kind.next, pixels.map_or(0., |m| m.next));
+    match (previous, next) {
+        (Length::Fixed(_), Length::Fixed(_)) => {
+            LengthType::Static(Length::Fixed(pixels.map_or(0., |m| m.value)))
+        }
+        (previous, next) if previous == next => LengthType::Static(next),
+        (previous, next) => LengthType::Blend(previous, next, kind.percent),
+    }
+}
+
+// Whether the appearance value with the set flag at `set` is set, and whether it
+// was set before the current link. Lazy keyframes give values that were never set
+// a flag of `0.`, so overshooting eases can take the values themselves anywhere.
+fn is_set(id: &widget::Id, timeline: &Timeline, set: usize) -> Option<bool> {
+    timeline
+        .get(id, set)
+        .filter(|m| m.next != 0.)
+        .map(|m| m.previous != 0.)
+}
+
+// An appearance value, or `None` if no keyframe has set it yet.
+// Blends from an unset value snap to the value being blended to.
+// Flags are steps, so under `Speed` the value blends in from zero instead.
+fn get_appearance_value(
+    id: &widget::Id,
+    timeline: &Timeline,
+    index: usize,
+    set: usize,
+) -> Option<f32> {
+    let was_set = is_set(id, timeline, set)?;
     timeline
         .get(id, index)
-        .map_or(default, |m| Length::Fixed(m.value))
+        .map(|m| if was_set { m.value.max(0.) } else { m.next })
+}
+
+// Colors are blended as a whole in `space`, so each channel is only used
+// to find the two colors and how far along the blend between them is.
+fn get_color(
+    id: &widget::Id,
+    timeline: &Timeline,
+    index: usize,
+    set: usize,
+    space: ColorSpace,
+) -> Option<Color> {
+    let was_set = is_set(id, timeline, set)?;
+    let channels: Vec<Interped> = (0..4)
+        .map(|offset| timeline.get(id, index + offset))
+        .collect::<Option<_>>()?;
+    let next = color(&channels, |m| m.next);
+    if !was_set {
+        return Some(next);
+    }
+    let percent = percent(&channels);
+    Some(space.mix(color(&channels, |m| m.previous), next, percent))
+}
+
+// Backgrounds are blended as a whole too, from the solid color at `index`
+// and the gradient at `gradient`. See `gradient_angle` for how they are stored.
+fn get_background(
+    id: &widget::Id,
+    timeline: &Timeline,
+    index: usize,
+    gradient: usize,
+    set: usize,
+    space: ColorSpace,
+) -> Option<Background> {
+    let was_set = is_set(id, timeline, set)?;
+    let stops = timeline.get(id, gradient + 1)?;
+    // The solid color, the angle, then the offset and color of every stop.
+    let channels: Vec<Interped> = (index..index + 4)
+        .chain(std::iter::once(gradient))
+        .chain(gradient + 2..gradient + 2 + STOP_COLUMNS)
+        .map(|index| timeline.get(id, index))
+        .collect::<Option<_>>()?;
+    let background = |get: fn(&Interped) -> f32, stops: f32| {
+        if stops < 1. {
+            return Background::Color(color(&channels, get));
+        }
+        let stops = channels[5..].chunks(5).take(stops as usize).map(|stop| {
+            Some(ColorStop {
+                offset: get(&stop[0]).clamp(0., 1.),
+                color: color(&stop[1..], get),
+            })
+        });
+        Background::from(Linear {
+            angle: Radians(get(&channels[4])),
+            stops: static_array_from_iter(stops),
+        })
+    };
+    let next = background(|m| m.next, stops.next);
+    if !was_set {
+        return Some(next);
+    }
+    let previous = background(|m| m.previous, stops.previous);
+    blend_backgrounds(Some(previous), Some(next), percent(&channels), space)
+}
+
+// The color in the first four channels.
+fn color(channels: &[Interped], get: fn(&Interped) -> f32) -> Color {
+    let [r, g, b, a] = [0, 1, 2, 3].map(|i| get(&channels[i]).clamp(0., 1.));
+    Color::from_rgba(r, g, b, a)
+}
+
+// How far along the blend between the previous and next values the channels
+// are. The channel that changes the most gives the most precise percent.
+fn percent(channels: &[Interped]) -> f32 {
+    channels
+        .iter()
+        .max_by(|a, b| {
+            (a.next - a.previous)
+                .abs()
+                .total_cmp(&(b.next - b.previous).abs())
+        })
+        .filter(|m| m.next != m.previous)
+        .map_or(1., |m| (m.value - m.previous) / (m.next - m.previous))
+}
+
+/// Get the color space set by keyframes at `property`,
+/// or the [`Timeline`]'s if no keyframe has set one.
+pub(crate) fn get_color_space(
+    id: &widget::Id,
+    timeline: &Timeline,
+    property: impl PropertyKey,
+) -> ColorSpace {
+    timeline
+        .get(id, property)
+        .and_then(|m| ColorSpace::from_f32(m.next))
+        .unwrap_or(timeline.color_space())
+}
+
+/// Get the appearance values set by keyframes, starting at `property`.
+/// The columns are background (4), text color (4), border color (4),
+/// border width and border radius. Whether each of those five is set
+/// is stored in five step columns, starting at `set`. A gradient background
+/// is stored in more columns, starting at `gradient`.
+/// Colors are blended in `space`.
+pub(crate) fn get_appearance(
+    id: &widget::Id,
+    timeline: &Timeline,
+    property: impl PropertyKey,
+    set: impl PropertyKey,
+    gradient: impl PropertyKey,
+    space: ColorSpace,
+) -> AppearanceOverride {
+    let index = property.index();
+    let set = set.index();
+    AppearanceOverride {
+        background: get_background(id, timeline, index, gradient.index(), set, space),
+        text_color: get_color(id, timeline, index + 4, set + 1, space),
+        border_color: get_color(id, timeline, index + 8, set + 2, space),
+        border_width: get_appearance_value(id, timeline, index + 12, set + 3),
+        border_radius: get_appearance_value(id, timeline, index + 13, set + 4),
+    }
 }
 
-fn as_f32(length: Option<Length>) -> Option<f32> {
+// Widgets from iced only understand `Length`. Blends that need to be resolved at
+// layout time wrap the content in a [`Resize`], and the widget shrinks to fit it.
+// `Fixed` lengths are the size of the whole widget, so the padding is taken off.
+fn resize<'a, Message, Theme, Renderer>(
+    width: LengthType,
+    height: LengthType,
+    padding: Padding,
+    content: Element<'a, Message, Theme, Renderer>,
+) -> (Length, Length, Element<'a, Message, Theme, Renderer>)
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: IcedRenderer + 'a,
+{
+    let inner = |length: LengthType, padding: f32| {
+        let inner = |length| match length {
+            Length::Fixed(pixels) => Length::Fixed((pixels - padding).max(0.)),
+            length => length,
+        };
+        match length {
+            LengthType::Static(length) => LengthType::Static(inner(length)),
+            LengthType::Blend(one, two, percent) => {
+                LengthType::Blend(inner(one), inner(two), percent)
+            }
+        }
+    };
+    match (width, height) {
+        (LengthType::Static(width), LengthType::Static(height)) => (width, height, content),
+        (width, height) => {
+            let resize = Resize::new(content)
+                .width(inner(width, padding.horizontal()))
+                .height(inner(height, padding.vertical()));
+            (Length::Shrink, Length::Shrink, resize.into())
+        }
+    }
+}
+
+// A length is stored as two properties. Its pixels, that only `Fixed` lengths
+// set, and its kind, a step that is never blended. `Fill` is `FillPortion(1)`.
+pub(crate) const FIXED: f32 = 0.;
+const SHRINK: f32 = -1.;
+
+// The pixels of a length, if it is `Fixed`.
+pub(crate) fn pixels(length: Option<Length>) -> Option<f32> {
     match length {
-        Some(Length::Fixed(i)) => Some(i),
+        Some(Length::Fixed(pixels)) => Some(pixels),
         _ => None,
     }
 }
+
+// The kind of a length.
+pub(crate) fn kind(length: Option<Length>) -> Option<f32> {
+    length.map(|length| match length {
+        Length::Fixed(_) => FIXED,
+        Length::Fill => 1.,
+        Length::FillPortion(portion) => f32::from(portion),
+        Length::Shrink => SHRINK,
+    })
+}
+
+fn as_length(kind: f32, pixels: f32) -> Length {
+    if kind == FIXED {
+        Length::Fixed(pixels)
+    } else if kind == SHRINK {
+        Length::Shrink
+    } else if kind == 1. {
+        Length::Fill
+    } else {
+        Length::FillPortion(kind as u16)
+    }
+}
+
+// A background is stored as a solid color, and the angle of a gradient, its
+// number of stops, a step that is `0.` for a solid color, and the offset and
+// color of each stop. A gradient's solid color is its first stop, and a solid
+// color is a gradient of that one color, so every keyframe sets every column
+// and they all blend together.
+pub(crate) const STOP_COLUMNS: usize = 5 * MAX_STOPS;
+
+// The most stops a `Linear` gradient can hold.
+const MAX_STOPS: usize = 8;
+
+// The angle of a gradient, `0.` for a solid color.
+pub(crate) fn gradient_angle(background: Background) -> f32 {
+    match background {
+        Background::Color(_) => 0.,
+        Background::Gradient(Gradient::Linear(linear)) => linear.angle.0,
+    }
+}
+
+// The number of stops of a gradient, `0.` for a solid color.
+pub(crate) fn gradient_stops(background: Background) -> f32 {
+    match background {
+        Background::Color(_) => 0.,
+        Background::Gradient(Gradient::Linear(linear)) => {
+            linear.stops.iter().flatten().count() as f32
+        }
+    }
+}
+
+// The solid color of a background, the first stop of a gradient.
+pub(crate) fn solid_color(background: Background) -> Color {
+    color_stops(background)
+        .first()
+        .map_or(Color::TRANSPARENT, |stop| stop.color)
+}
+
+// One of the `STOP_COLUMNS` values of a background's stops.
+// Stops past the end of a gradient repeat its last stop.
+pub(crate) fn stop_value(background: Background, column: usize) -> f32 {
+    let stops = color_stops(background);
+    let stop = stops
+        .get(column / 5)
+        .or(stops.last())
+        .copied()
+        .unwrap_or_default();
+    let ColorStop { offset, color } = stop;
+    [offset, color.r, color.g, color.b, color.a][column % 5]
+}
+
+fn color_stops(background: Background) -> Vec<ColorStop> {
+    match background {
+        Background::Color(color) => vec![ColorStop { offset: 0., color }],
+        Background::Gradient(Gradient::Linear(linear)) => {
+            linear.stops.iter().flatten().copied().collect()
+        }
+    }
+}
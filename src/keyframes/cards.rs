@@ -1,42 +1,20 @@
-use cosmic::iced_core::widget::Id as IcedId;
 use cosmic::widget::icon::Handle;
 use cosmic::Element;
 
-use crate::keyframes::Repeat;
 use crate::timeline::Frame;
-use crate::{cards, chain, lazy::cards as lazy, Duration, Ease, Linear, MovementType};
+use crate::{cards, chain, keyframe, lazy::cards as lazy, properties, Duration};
 
-/// A Cards's animation Id. Used for linking animation built in `update()` with widget output in `view()`
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Id(IcedId);
 const ANIM_DURATION: f32 = 100.;
 
-impl Id {
-    /// Creates a custom [`Id`].
-    pub fn new(id: impl Into<std::borrow::Cow<'static, str>>) -> Self {
-        Self(IcedId::new(id))
-    }
-
-    /// Creates a unique [`Id`].
-    ///
-    /// This function produces a different [`Id`] every time it is called.
-    #[must_use]
-    pub fn unique() -> Self {
-        Self(IcedId::unique())
-    }
-
-    /// Used by [`chain!`] macro
-    #[must_use]
-    pub fn into_chain(self) -> Chain {
-        Chain::new(self)
-    }
-
-    /// Used by [`chain!`] macro
-    #[must_use]
-    pub fn into_chain_with_children(self, children: Vec<Cards>) -> Chain {
-        Chain::with_children(self, children)
+keyframe! {
+    #[derive(Debug, Clone, Copy)]
+    pub struct Cards {
+        percent: f32 = 1.0,
+        blend_velocity: bool,
     }
+}
 
+impl Id {
     /// Used by [`crate::anim!`] macro
     #[allow(clippy::too_many_arguments)]
     pub fn as_widget<'a, Message, F>(
@@ -70,71 +48,7 @@ impl Id {
     }
 }
 
-impl From<Id> for IcedId {
-    fn from(id: Id) -> Self {
-        id.0
-    }
-}
-
-#[derive(Debug, Clone)]
-/// An animation, where each keyframe is "chained" together.
-pub struct Chain {
-    id: Id,
-    links: Vec<Cards>,
-    repeat: Repeat,
-}
-
 impl Chain {
-    /// Crate a new [`Cards`] animation chain.
-    /// You probably don't want to use use directly, and should
-    /// use the [`chain`] macro.
-    #[must_use]
-    pub fn new(id: Id) -> Self {
-        Chain {
-            id,
-            links: Vec::new(),
-            repeat: Repeat::Never,
-        }
-    }
-
-    /// Create a chain pre-fulled with children.
-    /// You probably don't want to use use directly, and should
-    /// use the [`chain`] macro.
-    #[must_use]
-    pub fn with_children(id: Id, children: Vec<Cards>) -> Self {
-        Chain {
-            id,
-            links: children,
-            repeat: Repeat::Never,
-        }
-    }
-
-    /// Link another keyframe, (very similar to push)
-    /// You probably don't want to use use directly, and should
-    /// use the [`chain`] macro.
-    #[must_use]
-    pub fn link(mut self, toggler: Cards) -> Self {
-        self.links.push(toggler);
-        self
-    }
-
-    /// Sets the animation to loop forever.
-    #[must_use]
-    pub fn loop_forever(mut self) -> Self {
-        self.repeat = Repeat::Forever;
-        self
-    }
-
-    /// Sets the animation to only loop once.
-    /// This is the default, and only useful to
-    /// stop an animation that was previously set
-    /// to loop forever.
-    #[must_use]
-    pub fn loop_once(mut self) -> Self {
-        self.repeat = Repeat::Never;
-        self
-    }
-
     /// Returns the default animation for animating the cards to "on"
     #[must_use]
     pub fn on(id: Id, anim_multiplier: f32) -> Self {
@@ -158,51 +72,7 @@ impl Chain {
     }
 }
 
-impl From<Chain> for crate::timeline::Chain {
-    fn from(chain: Chain) -> Self {
-        crate::timeline::Chain::new(
-            chain.id.into(),
-            chain.repeat,
-            chain
-                .links
-                .into_iter()
-                .map(std::convert::Into::into)
-                .collect::<Vec<_>>(),
-        )
-    }
-}
-
-#[must_use = "Keyframes are intended to be used in an animation chain."]
-#[derive(Debug, Clone, Copy)]
-///
-pub struct Cards {
-    at: MovementType,
-    ease: Ease,
-    percent: f32,
-    is_eager: bool,
-}
-
 impl Cards {
-    pub fn new(at: impl Into<MovementType>) -> Cards {
-        let at = at.into();
-        Cards {
-            at,
-            ease: Linear::InOut.into(),
-            percent: 1.0,
-            is_eager: true,
-        }
-    }
-
-    pub fn lazy(at: impl Into<MovementType>) -> Cards {
-        let at = at.into();
-        Cards {
-            at,
-            ease: Linear::InOut.into(),
-            percent: 1.0,
-            is_eager: false,
-        }
-    }
-
     #[allow(clippy::too_many_arguments)]
     pub fn as_widget<'a, Message, F>(
         id: Id,
@@ -233,7 +103,7 @@ impl Cards {
         )
         .percent(
             timeline
-                .get(&id.into(), 0)
+                .get(&id.into(), Cards::PERCENT)
                 .map_or(if expanded { 1.0 } else { 0.0 }, |m| m.value),
         )
     }
@@ -243,19 +113,18 @@ impl Cards {
         self
     }
 
-    pub fn ease<E: Into<Ease>>(mut self, ease: E) -> Self {
-        self.ease = ease.into();
+    // does nothing if eager
+    pub fn blend_velocity(mut self) -> Self {
+        self.blend_velocity = true;
         self
     }
 }
 
-#[rustfmt::skip]
-impl From<Cards> for Vec<Option<Frame>> {
-    fn from(cards: Cards) -> Vec<Option<Frame>> {
-      if cards.is_eager {
-        vec![Some(Frame::eager(cards.at, cards.percent, cards.ease))]  // 0 = animation percent completion
-      } else {
-        vec![Some(Frame::lazy(cards.at, 0., cards.ease))] // lazy evaluates for all values
-      }
+properties! {
+    Cards(cards) {
+        /// The percent completion of the animation.
+        PERCENT =>
+            Some(Frame::eager(cards.at, cards.percent, cards.ease)),
+            Some(Frame::lazy(cards.at, 0., cards.ease).with_velocity(cards.blend_velocity));
     }
 }
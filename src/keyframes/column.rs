@@ -1,173 +1,139 @@
 use crate::reexports::iced_core::{
     widget::Id as IcedId, Length, Padding, Pixels, Renderer as IcedRenderer,
 };
-use crate::reexports::iced_widget;
 
-use crate::keyframes::{as_f32, get_length, Repeat};
-use crate::timeline::Frame;
-use crate::{Ease, Linear, MovementType};
-
-/// A Column's animation Id. Used for linking animation built in `update()` with widget output in `view()`
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Id(IcedId);
-
-impl Id {
-    /// Creates a custom [`Id`].
-    pub fn new(id: impl Into<std::borrow::Cow<'static, str>>) -> Self {
-        Self(IcedId::new(id))
-    }
-
-    /// Creates a unique [`Id`].
-    ///
-    /// This function produces a different [`Id`] every time it is called.
-    #[must_use]
-    pub fn unique() -> Self {
-        Self(IcedId::unique())
-    }
-
-    /// Used by [`crate::chain!`] macro
-    #[must_use]
-    pub fn into_chain(self) -> Chain {
-        Chain::new(self)
-    }
-
-    /// Used by [`crate::chain!`] macro
-    #[must_use]
-    pub fn into_chain_with_children(self, children: Vec<Column>) -> Chain {
-        Chain::with_children(self, children)
+use crate::keyframes::{get_length, kind, pixels, timing_overrides, Timing, FIXED};
+use crate::{keyframe, properties};
+
+keyframe! {
+    #[derive(Debug, Clone, Copy)]
+    pub struct Column {
+        spacing: Option<f32>,
+        padding: Option<Padding>,
+        width: Option<Length>,
+        height: Option<Length>,
+        blend_velocity: bool,
+        spacing_timing: Timing,
+        padding_timing: Timing,
+        width_timing: Timing,
+        height_timing: Timing,
     }
+}
 
+impl Id {
     /// Used by [`crate::anim!`] macro
+    ///
+    /// Returns a [`crate::widget::Column`], not an iced `Column`, so blends between
+    /// `Fixed`, `Fill`, `FillPortion` and `Shrink` are resolved at layout time.
+    /// It builds the same way, and turns into an `Element`.
     #[must_use]
     pub fn as_widget<'a, Message, Theme, Renderer>(
         self,
         timeline: &crate::Timeline,
-    ) -> iced_widget::Column<'a, Message, Theme, Renderer>
+    ) -> crate::widget::Column<'a, Message, Theme, Renderer>
     where
         Renderer: IcedRenderer,
     {
         Column::as_widget(self, timeline)
     }
-}
-
-impl From<Id> for IcedId {
-    fn from(id: Id) -> Self {
-        id.0
-    }
-}
-
-#[derive(Debug)]
-pub struct Chain {
-    id: Id,
-    links: Vec<Column>,
-    repeat: Repeat,
-}
-
-impl Chain {
-    pub fn new(id: Id) -> Self {
-        Chain {
-            id,
-            links: Vec::new(),
-            repeat: Repeat::Never,
-        }
-    }
-
-    pub fn with_children(id: Id, children: Vec<Column>) -> Self {
-        Chain {
-            id,
-            links: children,
-            repeat: Repeat::Never,
-        }
-    }
-
-    pub fn link(mut self, container: Column) -> Self {
-        self.links.push(container);
-        self
-    }
 
-    pub fn loop_forever(mut self) -> Self {
-        self.repeat = Repeat::Forever;
-        self
-    }
-
-    pub fn loop_once(mut self) -> Self {
-        self.repeat = Repeat::Never;
-        self
-    }
-}
-
-impl From<Chain> for crate::timeline::Chain {
-    fn from(chain: Chain) -> Self {
-        crate::timeline::Chain::new(
-            chain.id.into(),
-            chain.repeat,
-            chain
-                .links
-                .into_iter()
-                .map(std::convert::Into::into)
-                .collect::<Vec<_>>(),
-        )
+    /// Like `as_widget`, but children are keyed, and glide to their new
+    /// position when they are reordered, inserted or removed.
+    /// The glides are published with [`crate::widget::Flip::on_move`].
+    #[must_use]
+    pub fn as_flip_widget<'a, Message, Theme, Renderer>(
+        self,
+        timeline: &'a crate::Timeline,
+    ) -> crate::widget::Flip<'a, Message, Theme, Renderer>
+    where
+        Renderer: IcedRenderer,
+    {
+        Column::as_flip_widget(self, timeline)
     }
 }
 
-#[must_use = "Keyframes are intended to be used in an animation chain."]
-#[derive(Debug, Clone, Copy)]
-pub struct Column {
-    at: MovementType,
-    ease: Ease,
-    spacing: Option<f32>,
-    padding: Option<Padding>,
-    width: Option<Length>,
-    height: Option<Length>,
-    is_eager: bool,
-}
-
 impl Column {
-    pub fn new(at: impl Into<MovementType>) -> Column {
-        let at = at.into();
-        Column {
-            at,
-            ease: Linear::InOut.into(),
-            spacing: None,
-            width: None,
-            height: None,
-            padding: None,
-            is_eager: true,
-        }
-    }
-
-    pub fn lazy(at: impl Into<MovementType>) -> Column {
-        let at = at.into();
-        Column {
-            at,
-            ease: Linear::InOut.into(),
-            spacing: None,
-            width: None,
-            height: None,
-            padding: None,
-            is_eager: false,
-        }
-    }
-
     pub fn as_widget<'a, Message, Theme, Renderer>(
         id: Id,
         timeline: &crate::Timeline,
-    ) -> iced_widget::Column<'a, Message, Theme, Renderer>
+    ) -> crate::widget::Column<'a, Message, Theme, Renderer>
+    where
+        Renderer: IcedRenderer,
+    {
+        let id: IcedId = id.into();
+
+        crate::widget::Column::new()
+            .spacing(timeline.get(&id, Column::SPACING).map_or(0., |m| m.value))
+            .padding([
+                timeline
+                    .get(&id, Column::PADDING_TOP)
+                    .map_or(0., |m| m.value),
+                timeline
+                    .get(&id, Column::PADDING_RIGHT)
+                    .map_or(0., |m| m.value),
+                timeline
+                    .get(&id, Column::PADDING_BOTTOM)
+                    .map_or(0., |m| m.value),
+                timeline
+                    .get(&id, Column::PADDING_LEFT)
+                    .map_or(0., |m| m.value),
+            ])
+            .width(get_length(
+                &id,
+                timeline,
+                Column::WIDTH,
+                Column::WIDTH_KIND,
+                Length::Shrink,
+            ))
+            .height(get_length(
+                &id,
+                timeline,
+                Column::HEIGHT,
+                Column::HEIGHT_KIND,
+                Length::Shrink,
+            ))
+    }
+
+    pub fn as_flip_widget<'a, Message, Theme, Renderer>(
+        id: Id,
+        timeline: &'a crate::Timeline,
+    ) -> crate::widget::Flip<'a, Message, Theme, Renderer>
     where
         Renderer: IcedRenderer,
     {
         let id: IcedId = id.into();
 
-        iced_widget::Column::new()
-            .spacing(timeline.get(&id, 0).map_or(0., |m| m.value))
+        crate::widget::Flip::column()
+            .timeline(timeline)
+            .spacing(timeline.get(&id, Column::SPACING).map_or(0., |m| m.value))
             .padding([
-                timeline.get(&id, 1).map_or(0., |m| m.value),
-                timeline.get(&id, 2).map_or(0., |m| m.value),
-                timeline.get(&id, 3).map_or(0., |m| m.value),
-                timeline.get(&id, 4).map_or(0., |m| m.value),
+                timeline
+                    .get(&id, Column::PADDING_TOP)
+                    .map_or(0., |m| m.value),
+                timeline
+                    .get(&id, Column::PADDING_RIGHT)
+                    .map_or(0., |m| m.value),
+                timeline
+                    .get(&id, Column::PADDING_BOTTOM)
+                    .map_or(0., |m| m.value),
+                timeline
+                    .get(&id, Column::PADDING_LEFT)
+                    .map_or(0., |m| m.value),
             ])
-            .width(get_length(&id, timeline, 5, Length::Shrink))
-            .height(get_length(&id, timeline, 6, Length::Shrink))
+            .width(get_length(
+                &id,
+                timeline,
+                Column::WIDTH,
+                Column::WIDTH_KIND,
+                Length::Shrink,
+            ))
+            .height(get_length(
+                &id,
+                timeline,
+                Column::HEIGHT,
+                Column::HEIGHT_KIND,
+                Length::Shrink,
+            ))
     }
 
     pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
@@ -190,26 +156,57 @@ impl Column {
         self
     }
 
-    pub fn ease<E: Into<Ease>>(mut self, ease: E) -> Self {
-        self.ease = ease.into();
+    timing_overrides! {
+        spacing_timing: spacing_at, spacing_ease;
+        padding_timing: padding_at, padding_ease;
+        width_timing: width_at, width_ease;
+        height_timing: height_at, height_ease;
+    }
+
+    // does nothing if eager
+    pub fn blend_velocity(mut self) -> Self {
+        self.blend_velocity = true;
         self
     }
 }
 
-#[rustfmt::skip]
-impl From<Column> for Vec<Option<Frame>> {
-    fn from(column: Column) -> Vec<Option<Frame>> {
-      if column.is_eager {
-        vec![column.spacing.map(|s| Frame::eager(column.at, s, column.ease)),        // 0 = spacing
-             column.padding.map(|p| Frame::eager(column.at, p.top, column.ease)),    // 1 = padding[0] (top)
-             column.padding.map(|p| Frame::eager(column.at, p.right, column.ease)),  // 2 = padding[1] (right)
-             column.padding.map(|p| Frame::eager(column.at, p.bottom, column.ease)), // 3 = padding[2] (bottom)
-             column.padding.map(|p| Frame::eager(column.at, p.left, column.ease)),   // 4 = padding[3] (left)
-             as_f32(column.width).map(|w| Frame::eager(column.at, w, column.ease)),  // 5 = width
-             as_f32(column.height).map(|h| Frame::eager(column.at, h, column.ease)), // 6 = height
-        ]
-      } else {
-        vec![Some(Frame::lazy(column.at, 0., column.ease)); 7] // lazy evaluates for all values
-      }
+properties! {
+    Column(column) {
+        /// The spacing between children.
+        SPACING =>
+            column.spacing.map(|s| column.spacing_timing.eager(column.at, s, column.ease)),
+            Some(column.spacing_timing.lazy(column.at, 0., column.ease).with_velocity(column.blend_velocity));
+        /// The top padding.
+        PADDING_TOP =>
+            column.padding.map(|p| column.padding_timing.eager(column.at, p.top, column.ease)),
+            Some(column.padding_timing.lazy(column.at, 0., column.ease).with_velocity(column.blend_velocity));
+        /// The right padding.
+        PADDING_RIGHT =>
+            column.padding.map(|p| column.padding_timing.eager(column.at, p.right, column.ease)),
+            Some(column.padding_timing.lazy(column.at, 0., column.ease).with_velocity(column.blend_velocity));
+        /// The bottom padding.
+        PADDING_BOTTOM =>
+            column.padding.map(|p| column.padding_timing.eager(column.at, p.bottom, column.ease)),
+            Some(column.padding_timing.lazy(column.at, 0., column.ease).with_velocity(column.blend_velocity));
+        /// The left padding.
+        PADDING_LEFT =>
+            column.padding.map(|p| column.padding_timing.eager(column.at, p.left, column.ease)),
+            Some(column.padding_timing.lazy(column.at, 0., column.ease).with_velocity(column.blend_velocity));
+        /// The width in pixels, when it is `Fixed`.
+        WIDTH =>
+            pixels(column.width).map(|w| column.width_timing.eager(column.at, w, column.ease)),
+            Some(column.width_timing.lazy(column.at, 0., column.ease).with_velocity(column.blend_velocity));
+        /// The kind of width, `Fixed`, `Fill`, `FillPortion` or `Shrink`.
+        WIDTH_KIND =>
+            kind(column.width).map(|k| column.width_timing.step(column.at, k, column.ease)),
+            Some(column.width_timing.lazy_step(column.at, FIXED, column.ease));
+        /// The height in pixels, when it is `Fixed`.
+        HEIGHT =>
+            pixels(column.height).map(|h| column.height_timing.eager(column.at, h, column.ease)),
+            Some(column.height_timing.lazy(column.at, 0., column.ease).with_velocity(column.blend_velocity));
+        /// The kind of height, `Fixed`, `Fill`, `FillPortion` or `Shrink`.
+        HEIGHT_KIND =>
+            kind(column.height).map(|k| column.height_timing.step(column.at, k, column.ease)),
+            Some(column.height_timing.lazy_step(column.at, FIXED, column.ease));
     }
 }
@@ -3,40 +3,27 @@ use crate::reexports::iced_core::{
 };
 use crate::reexports::{iced_style, iced_widget};
 
-use crate::keyframes::{as_f32, get_length, Repeat};
-use crate::timeline::Frame;
-use crate::{Ease, Linear, MovementType};
-
-/// A Container's animation Id. Used for linking animation built in `update()` with widget output in `view()`
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Id(IcedId);
-
-impl Id {
-    /// Creates a custom [`Id`].
-    pub fn new(id: impl Into<std::borrow::Cow<'static, str>>) -> Self {
-        Self(IcedId::new(id))
-    }
-
-    /// Creates a unique [`Id`].
-    ///
-    /// This function produces a different [`Id`] every time it is called.
-    #[must_use]
-    pub fn unique() -> Self {
-        Self(IcedId::unique())
-    }
-
-    /// Used by [`crate::chain!`] macro
-    #[must_use]
-    pub fn into_chain(self) -> Chain {
-        Chain::new(self)
-    }
-
-    /// Used by [`crate::chain!`] macro
-    #[must_use]
-    pub fn into_chain_with_children(self, children: Vec<Container>) -> Chain {
-        Chain::with_children(self, children)
+use crate::keyframes::{get_length, kind, pixels, resize, timing_overrides, Timing, FIXED};
+use crate::{keyframe, properties};
+
+keyframe! {
+    #[derive(Debug, Clone, Copy)]
+    pub struct Container {
+        width: Option<Length>,
+        height: Option<Length>,
+        padding: Option<Padding>,
+        max_width: Option<f32>,
+        max_height: Option<f32>,
+        blend_velocity: bool,
+        width_timing: Timing,
+        height_timing: Timing,
+        padding_timing: Timing,
+        max_width_timing: Timing,
+        max_height_timing: Timing,
     }
+}
 
+impl Id {
     /// Used by [`crate::anim!`] macro
     pub fn as_widget<'a, Message, Theme, Renderer>(
         self,
@@ -44,133 +31,73 @@ impl Id {
         content: impl Into<Element<'a, Message, Theme, Renderer>>,
     ) -> iced_widget::Container<'a, Message, Theme, Renderer>
     where
-        Renderer: IcedRenderer,
-        Theme: iced_style::container::StyleSheet,
+        Message: 'a,
+        Renderer: IcedRenderer + 'a,
+        Theme: iced_style::container::StyleSheet + 'a,
     {
         Container::as_widget(self, timeline, content)
     }
 }
 
-impl From<Id> for IcedId {
-    fn from(id: Id) -> Self {
-        id.0
-    }
-}
-
-#[derive(Debug)]
-pub struct Chain {
-    id: Id,
-    links: Vec<Container>,
-    repeat: Repeat,
-}
-
-impl Chain {
-    pub fn new(id: Id) -> Self {
-        Chain {
-            id,
-            links: Vec::new(),
-            repeat: Repeat::Never,
-        }
-    }
-
-    pub fn with_children(id: Id, children: Vec<Container>) -> Self {
-        Chain {
-            id,
-            links: children,
-            repeat: Repeat::Never,
-        }
-    }
-
-    pub fn link(mut self, container: Container) -> Self {
-        self.links.push(container);
-        self
-    }
-
-    pub fn loop_forever(mut self) -> Self {
-        self.repeat = Repeat::Forever;
-        self
-    }
-
-    pub fn loop_once(mut self) -> Self {
-        self.repeat = Repeat::Never;
-        self
-    }
-}
-
-impl From<Chain> for crate::timeline::Chain {
-    fn from(chain: Chain) -> Self {
-        crate::timeline::Chain::new(
-            chain.id.into(),
-            chain.repeat,
-            chain.links.into_iter().map(Into::into).collect::<Vec<_>>(),
-        )
-    }
-}
-
-#[must_use = "Keyframes are intended to be used in an animation chain."]
-#[derive(Debug, Clone, Copy)]
-pub struct Container {
-    at: MovementType,
-    ease: Ease,
-    width: Option<Length>,
-    height: Option<Length>,
-    padding: Option<Padding>,
-    max_width: Option<f32>,
-    max_height: Option<f32>,
-    is_eager: bool,
-}
-
 impl Container {
-    pub fn new(at: impl Into<MovementType>) -> Container {
-        let at = at.into();
-        Container {
-            at,
-            ease: Linear::InOut.into(),
-            width: None,
-            height: None,
-            padding: None,
-            max_width: None,
-            max_height: None,
-            is_eager: true,
-        }
-    }
-
-    pub fn lazy(at: impl Into<MovementType>) -> Container {
-        let at = at.into();
-        Container {
-            at,
-            ease: Linear::InOut.into(),
-            width: None,
-            height: None,
-            padding: None,
-            max_width: None,
-            max_height: None,
-            is_eager: false,
-        }
-    }
-
     pub fn as_widget<'a, Message, Theme, Renderer>(
         id: Id,
         timeline: &crate::Timeline,
         content: impl Into<Element<'a, Message, Theme, Renderer>>,
     ) -> iced_widget::Container<'a, Message, Theme, Renderer>
     where
-        Renderer: IcedRenderer,
-        Theme: iced_style::container::StyleSheet,
+        Message: 'a,
+        Renderer: IcedRenderer + 'a,
+        Theme: iced_style::container::StyleSheet + 'a,
     {
         let id: IcedId = id.into();
+        let padding = Padding::from([
+            timeline
+                .get(&id, Container::PADDING_TOP)
+                .map_or(0., |m| m.value),
+            timeline
+                .get(&id, Container::PADDING_RIGHT)
+                .map_or(0., |m| m.value),
+            timeline
+                .get(&id, Container::PADDING_BOTTOM)
+                .map_or(0., |m| m.value),
+            timeline
+                .get(&id, Container::PADDING_LEFT)
+                .map_or(0., |m| m.value),
+        ]);
+        let (width, height, content) = resize(
+            get_length(
+                &id,
+                timeline,
+                Container::WIDTH,
+                Container::WIDTH_KIND,
+                Length::Shrink,
+            ),
+            get_length(
+                &id,
+                timeline,
+                Container::HEIGHT,
+                Container::HEIGHT_KIND,
+                Length::Shrink,
+            ),
+            padding,
+            content.into(),
+        );
 
         iced_widget::Container::new(content)
-            .width(get_length(&id, timeline, 0, Length::Shrink))
-            .height(get_length(&id, timeline, 1, Length::Shrink))
-            .padding([
-                timeline.get(&id, 2).map_or(0., |m| m.value),
-                timeline.get(&id, 3).map_or(0., |m| m.value),
-                timeline.get(&id, 4).map_or(0., |m| m.value),
-                timeline.get(&id, 5).map_or(0., |m| m.value),
-            ])
-            .max_width(timeline.get(&id, 6).map_or(f32::INFINITY, |m| m.value))
-            .max_height(timeline.get(&id, 7).map_or(f32::INFINITY, |m| m.value))
+            .width(width)
+            .height(height)
+            .padding(padding)
+            .max_width(
+                timeline
+                    .get(&id, Container::MAX_WIDTH)
+                    .map_or(f32::INFINITY, |m| m.value),
+            )
+            .max_height(
+                timeline
+                    .get(&id, Container::MAX_HEIGHT)
+                    .map_or(f32::INFINITY, |m| m.value),
+            )
     }
 
     pub fn width(mut self, width: impl Into<Length>) -> Self {
@@ -198,27 +125,62 @@ impl Container {
         self
     }
 
-    pub fn ease<E: Into<Ease>>(mut self, ease: E) -> Self {
-        self.ease = ease.into();
+    timing_overrides! {
+        width_timing: width_at, width_ease;
+        height_timing: height_at, height_ease;
+        padding_timing: padding_at, padding_ease;
+        max_width_timing: max_width_at, max_width_ease;
+        max_height_timing: max_height_at, max_height_ease;
+    }
+
+    // does nothing if eager
+    pub fn blend_velocity(mut self) -> Self {
+        self.blend_velocity = true;
         self
     }
 }
 
-#[rustfmt::skip]
-impl From<Container> for Vec<Option<Frame>> {
-    fn from(container: Container) -> Vec<Option<Frame>> {
-      if container.is_eager {
-        vec![as_f32(container.width).map(|w| Frame::eager(container.at, w, container.ease)),  // 0 = width
-             as_f32(container.height).map(|h| Frame::eager(container.at, h, container.ease)), // 1 = height
-             container.padding.map(|p| Frame::eager(container.at, p.top, container.ease)),    // 2 = padding[0] (top)
-             container.padding.map(|p| Frame::eager(container.at, p.right, container.ease)),  // 3 = padding[1] (right)
-             container.padding.map(|p| Frame::eager(container.at, p.bottom, container.ease)), // 4 = padding[2] (bottom)
-             container.padding.map(|p| Frame::eager(container.at, p.left, container.ease)),   // 5 = padding[3] (left)
-             container.max_width.map(|w| Frame::eager(container.at, w, container.ease)),      // 6 = max_width
-             container.max_height.map(|h| Frame::eager(container.at, h, container.ease)),     // 7 = max_height
-        ]
-      } else {
-        vec![Some(Frame::lazy(container.at, 0., container.ease)); 8] // lazy evaluates for all values
-      }
+properties! {
+    Container(container) {
+        /// The width in pixels, when it is `Fixed`.
+        WIDTH =>
+            pixels(container.width).map(|w| container.width_timing.eager(container.at, w, container.ease)),
+            Some(container.width_timing.lazy(container.at, 0., container.ease).with_velocity(container.blend_velocity));
+        /// The kind of width, `Fixed`, `Fill`, `FillPortion` or `Shrink`.
+        WIDTH_KIND =>
+            kind(container.width).map(|k| container.width_timing.step(container.at, k, container.ease)),
+            Some(container.width_timing.lazy_step(container.at, FIXED, container.ease));
+        /// The height in pixels, when it is `Fixed`.
+        HEIGHT =>
+            pixels(container.height).map(|h| container.height_timing.eager(container.at, h, container.ease)),
+            Some(container.height_timing.lazy(container.at, 0., container.ease).with_velocity(container.blend_velocity));
+        /// The kind of height, `Fixed`, `Fill`, `FillPortion` or `Shrink`.
+        HEIGHT_KIND =>
+            kind(container.height).map(|k| container.height_timing.step(container.at, k, container.ease)),
+            Some(container.height_timing.lazy_step(container.at, FIXED, container.ease));
+        /// The top padding.
+        PADDING_TOP =>
+            container.padding.map(|p| container.padding_timing.eager(container.at, p.top, container.ease)),
+            Some(container.padding_timing.lazy(container.at, 0., container.ease).with_velocity(container.blend_velocity));
+        /// The right padding.
+        PADDING_RIGHT =>
+            container.padding.map(|p| container.padding_timing.eager(container.at, p.right, container.ease)),
+            Some(container.padding_timing.lazy(container.at, 0., container.ease).with_velocity(container.blend_velocity));
+        /// The bottom padding.
+        PADDING_BOTTOM =>
+            container.padding.map(|p| container.padding_timing.eager(container.at, p.bottom, container.ease)),
+            Some(container.padding_timing.lazy(container.at, 0., container.ease).with_velocity(container.blend_velocity));
+        /// The left padding.
+        PADDING_LEFT =>
+            container.padding.map(|p| container.padding_timing.eager(container.at, p.left, container.ease)),
+            Some(container.padding_timing.lazy(container.at, 0., container.ease).with_velocity(container.blend_velocity));
+        /// The maximum width.
+        MAX_WIDTH =>
+            container.max_width.map(|w| container.max_width_timing.eager(container.at, w, container.ease)),
+            Some(container.max_width_timing.lazy(container.at, 0., container.ease).with_velocity(container.blend_velocity));
+        /// The maximum height.
+        MAX_HEIGHT =>
+            container.max_height.map(|h| container.max_height_timing.eager(container.at, h, container.ease)),
+            Some(container.max_height_timing.lazy(container.at, 0., container.ease).with_velocity(container.blend_velocity));
     }
 }
@@ -1,7 +1,7 @@
 #[cfg(feature = "libcosmic")]
 use crate::keyframes::Cards;
 use crate::keyframes::{
-    Button, Column, Container, Row, Space, StyleButton, StyleContainer, Toggler,
+    Button, Column, Container, Row, Space, StyleButton, StyleContainer, Toggler, Transform,
 };
 
 use crate::MovementType;
@@ -42,6 +42,12 @@ pub fn toggler(at: impl Into<MovementType>) -> Toggler {
     Toggler::new(at)
 }
 
+/// Create a transform keyframe.
+/// Needs to be added into a chain. See [`crate::chain!`] macro.
+pub fn transform(at: impl Into<MovementType>) -> Transform {
+    Transform::new(at)
+}
+
 #[cfg(feature = "libcosmic")]
 /// Create a cards keyframe.
 /// Needs to be added into a chain. See [`crate::chain!`] macro.
@@ -66,7 +72,7 @@ pub mod lazy {
     #[cfg(feature = "libcosmic")]
     use crate::keyframes::Cards;
     use crate::keyframes::{
-        Button, Column, Container, Row, Space, StyleButton, StyleContainer, Toggler,
+        Button, Column, Container, Row, Space, StyleButton, StyleContainer, Toggler, Transform,
     };
     use crate::MovementType;
 
@@ -106,6 +112,12 @@ pub mod lazy {
         Toggler::lazy(at)
     }
 
+    /// Create a lazy transform keyframe.
+    /// Needs to be added into a chain. See [`crate::chain!`] macro.
+    pub fn transform(at: impl Into<MovementType>) -> Transform {
+        Transform::lazy(at)
+    }
+
     #[cfg(feature = "libcosmic")]
     /// Create a lazy toggler keyframe.
     /// Needs to be added into a chain. See [`crate::chain!`] macro.
@@ -133,7 +145,7 @@ pub mod id {
     pub use crate::keyframes::{
         button::Id as Button, column::Id as Column, container::Id as Container, row::Id as Row,
         space::Id as Space, style_button::Id as StyleButton, style_container::Id as StyleContainer,
-        toggler::Id as Toggler,
+        toggler::Id as Toggler, transform::Id as Transform,
     };
 }
 
@@ -1,173 +1,131 @@
 use crate::reexports::iced_core::{
     widget::Id as IcedId, Length, Padding, Pixels, Renderer as IcedRenderer,
 };
-use crate::reexports::iced_widget;
 
-use crate::keyframes::{as_f32, get_length, Repeat};
-use crate::timeline::Frame;
-use crate::{Ease, Linear, MovementType};
-
-/// A Row's animation Id. Used for linking animation built in `update()` with widget output in `view()`
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Id(IcedId);
-
-impl Id {
-    /// Creates a custom [`Id`].
-    pub fn new(id: impl Into<std::borrow::Cow<'static, str>>) -> Self {
-        Self(IcedId::new(id))
-    }
-
-    /// Creates a unique [`Id`].
-    ///
-    /// This function produces a different [`Id`] every time it is called.
-    #[must_use]
-    pub fn unique() -> Self {
-        Self(IcedId::unique())
-    }
-
-    /// Used by [`crate::chain!`] macro
-    #[must_use]
-    pub fn into_chain(self) -> Chain {
-        Chain::new(self)
-    }
-
-    /// Used by [`crate::chain!`] macro
-    #[must_use]
-    pub fn into_chain_with_children(self, children: Vec<Row>) -> Chain {
-        Chain::with_children(self, children)
+use crate::keyframes::{get_length, kind, pixels, timing_overrides, Timing, FIXED};
+use crate::{keyframe, properties};
+
+keyframe! {
+    #[derive(Debug, Clone, Copy)]
+    pub struct Row {
+        spacing: Option<f32>,
+        padding: Option<Padding>,
+        width: Option<Length>,
+        height: Option<Length>,
+        blend_velocity: bool,
+        spacing_timing: Timing,
+        padding_timing: Timing,
+        width_timing: Timing,
+        height_timing: Timing,
     }
+}
 
+impl Id {
     /// Used by [`crate::anim!`] macro
+    ///
+    /// Returns a [`crate::widget::Row`], not an iced `Row`, so blends between
+    /// `Fixed`, `Fill`, `FillPortion` and `Shrink` are resolved at layout time.
+    /// It builds the same way, and turns into an `Element`.
     #[must_use]
     pub fn as_iced_widget<'a, Message, Theme, Renderer>(
         self,
         timeline: &crate::Timeline,
-    ) -> iced_widget::Row<'a, Message, Theme, Renderer>
+    ) -> crate::widget::Row<'a, Message, Theme, Renderer>
     where
         Renderer: IcedRenderer,
     {
         Row::as_iced_widget(self, timeline)
     }
-}
-
-impl From<Id> for IcedId {
-    fn from(id: Id) -> Self {
-        id.0
-    }
-}
-
-#[derive(Debug)]
-pub struct Chain {
-    id: Id,
-    links: Vec<Row>,
-    repeat: Repeat,
-}
-
-impl Chain {
-    pub fn new(id: Id) -> Self {
-        Chain {
-            id,
-            links: Vec::new(),
-            repeat: Repeat::Never,
-        }
-    }
-
-    pub fn with_children(id: Id, children: Vec<Row>) -> Self {
-        Chain {
-            id,
-            links: children,
-            repeat: Repeat::Never,
-        }
-    }
-
-    pub fn link(mut self, container: Row) -> Self {
-        self.links.push(container);
-        self
-    }
 
-    pub fn loop_forever(mut self) -> Self {
-        self.repeat = Repeat::Forever;
-        self
-    }
-
-    pub fn loop_once(mut self) -> Self {
-        self.repeat = Repeat::Never;
-        self
-    }
-}
-
-impl From<Chain> for crate::timeline::Chain {
-    fn from(chain: Chain) -> Self {
-        crate::timeline::Chain::new(
-            chain.id.into(),
-            chain.repeat,
-            chain
-                .links
-                .into_iter()
-                .map(std::convert::Into::into)
-                .collect::<Vec<_>>(),
-        )
+    /// Like `as_iced_widget`, but children are keyed, and glide to their new
+    /// position when they are reordered, inserted or removed.
+    /// The glides are published with [`crate::widget::Flip::on_move`].
+    #[must_use]
+    pub fn as_flip_widget<'a, Message, Theme, Renderer>(
+        self,
+        timeline: &'a crate::Timeline,
+    ) -> crate::widget::Flip<'a, Message, Theme, Renderer>
+    where
+        Renderer: IcedRenderer,
+    {
+        Row::as_flip_widget(self, timeline)
     }
 }
 
-#[must_use = "Keyframes are intended to be used in an animation chain."]
-#[derive(Debug, Clone, Copy)]
-pub struct Row {
-    at: MovementType,
-    ease: Ease,
-    spacing: Option<f32>,
-    padding: Option<Padding>,
-    width: Option<Length>,
-    height: Option<Length>,
-    is_eager: bool,
-}
-
 impl Row {
-    pub fn new(at: impl Into<MovementType>) -> Row {
-        let at = at.into();
-        Row {
-            at,
-            ease: Linear::InOut.into(),
-            spacing: None,
-            width: None,
-            height: None,
-            padding: None,
-            is_eager: true,
-        }
-    }
-
-    pub fn lazy(at: impl Into<MovementType>) -> Row {
-        let at = at.into();
-        Row {
-            at,
-            ease: Linear::InOut.into(),
-            spacing: None,
-            width: None,
-            height: None,
-            padding: None,
-            is_eager: false,
-        }
-    }
-
     pub fn as_iced_widget<'a, Message, Theme, Renderer>(
         id: Id,
         timeline: &crate::Timeline,
-    ) -> iced_widget::Row<'a, Message, Theme, Renderer>
+    ) -> crate::widget::Row<'a, Message, Theme, Renderer>
+    where
+        Renderer: IcedRenderer,
+    {
+        let id: IcedId = id.into();
+
+        crate::widget::Row::new()
+            .spacing(timeline.get(&id, Row::SPACING).map_or(0., |m| m.value))
+            .padding([
+                timeline.get(&id, Row::PADDING_TOP).map_or(0., |m| m.value),
+                timeline
+                    .get(&id, Row::PADDING_RIGHT)
+                    .map_or(0., |m| m.value),
+                timeline
+                    .get(&id, Row::PADDING_BOTTOM)
+                    .map_or(0., |m| m.value),
+                timeline.get(&id, Row::PADDING_LEFT).map_or(0., |m| m.value),
+            ])
+            .width(get_length(
+                &id,
+                timeline,
+                Row::WIDTH,
+                Row::WIDTH_KIND,
+                Length::Shrink,
+            ))
+            .height(get_length(
+                &id,
+                timeline,
+                Row::HEIGHT,
+                Row::HEIGHT_KIND,
+                Length::Shrink,
+            ))
+    }
+
+    pub fn as_flip_widget<'a, Message, Theme, Renderer>(
+        id: Id,
+        timeline: &'a crate::Timeline,
+    ) -> crate::widget::Flip<'a, Message, Theme, Renderer>
     where
         Renderer: IcedRenderer,
     {
         let id: IcedId = id.into();
 
-        iced_widget::Row::new()
-            .spacing(timeline.get(&id, 0).map_or(0., |m| m.value))
+        crate::widget::Flip::row()
+            .timeline(timeline)
+            .spacing(timeline.get(&id, Row::SPACING).map_or(0., |m| m.value))
             .padding([
-                timeline.get(&id, 1).map_or(0., |m| m.value),
-                timeline.get(&id, 2).map_or(0., |m| m.value),
-                timeline.get(&id, 3).map_or(0., |m| m.value),
-                timeline.get(&id, 4).map_or(0., |m| m.value),
+                timeline.get(&id, Row::PADDING_TOP).map_or(0., |m| m.value),
+                timeline
+                    .get(&id, Row::PADDING_RIGHT)
+                    .map_or(0., |m| m.value),
+                timeline
+                    .get(&id, Row::PADDING_BOTTOM)
+                    .map_or(0., |m| m.value),
+                timeline.get(&id, Row::PADDING_LEFT).map_or(0., |m| m.value),
             ])
-            .width(get_length(&id, timeline, 5, Length::Shrink))
-            .height(get_length(&id, timeline, 6, Length::Shrink))
+            .width(get_length(
+                &id,
+                timeline,
+                Row::WIDTH,
+                Row::WIDTH_KIND,
+                Length::Shrink,
+            ))
+            .height(get_length(
+                &id,
+                timeline,
+                Row::HEIGHT,
+                Row::HEIGHT_KIND,
+                Length::Shrink,
+            ))
     }
 
     pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
@@ -190,26 +148,57 @@ impl Row {
         self
     }
 
-    pub fn ease<E: Into<Ease>>(mut self, ease: E) -> Self {
-        self.ease = ease.into();
+    timing_overrides! {
+        spacing_timing: spacing_at, spacing_ease;
+        padding_timing: padding_at, padding_ease;
+        width_timing: width_at, width_ease;
+        height_timing: height_at, height_ease;
+    }
+
+    // does nothing if eager
+    pub fn blend_velocity(mut self) -> Self {
+        self.blend_velocity = true;
         self
     }
 }
 
-#[rustfmt::skip]
-impl From<Row> for Vec<Option<Frame>> {
-    fn from(row: Row) -> Vec<Option<Frame>> {
-      if row.is_eager {
-        vec![row.spacing.map(|s| Frame::eager(row.at, s, row.ease)),        // 0 = spacing
-             row.padding.map(|p| Frame::eager(row.at, p.top, row.ease)),    // 1 = padding[0] (top)
-             row.padding.map(|p| Frame::eager(row.at, p.right, row.ease)),  // 2 = padding[1] (right)
-             row.padding.map(|p| Frame::eager(row.at, p.bottom, row.ease)), // 3 = padding[2] (bottom)
-             row.padding.map(|p| Frame::eager(row.at, p.left, row.ease)),  // 4 = padding[3] (left)
-             as_f32(row.width).map(|w| Frame::eager(row.at, w, row.ease)),  // 5 = width
-             as_f32(row.height).map(|h| Frame::eager(row.at, h, row.ease)), // 6 = height
-        ]
-      } else {
-        vec![Some(Frame::lazy(row.at, 0., row.ease)); 7] // lazy evaluates for all values
-      }
+properties! {
+    Row(row) {
+        /// The spacing between children.
+        SPACING =>
+            row.spacing.map(|s| row.spacing_timing.eager(row.at, s, row.ease)),
+            Some(row.spacing_timing.lazy(row.at, 0., row.ease).with_velocity(row.blend_velocity));
+        /// The top padding.
+        PADDING_TOP =>
+            row.padding.map(|p| row.padding_timing.eager(row.at, p.top, row.ease)),
+            Some(row.padding_timing.lazy(row.at, 0., row.ease).with_velocity(row.blend_velocity));
+        /// The right padding.
+        PADDING_RIGHT =>
+            row.padding.map(|p| row.padding_timing.eager(row.at, p.right, row.ease)),
+            Some(row.padding_timing.lazy(row.at, 0., row.ease).with_velocity(row.blend_velocity));
+        /// The bottom padding.
+        PADDING_BOTTOM =>
+            row.padding.map(|p| row.padding_timing.eager(row.at, p.bottom, row.ease)),
+            Some(row.padding_timing.lazy(row.at, 0., row.ease).with_velocity(row.blend_velocity));
+        /// The left padding.
+        PADDING_LEFT =>
+            row.padding.map(|p| row.padding_timing.eager(row.at, p.left, row.ease)),
+            Some(row.padding_timing.lazy(row.at, 0., row.ease).with_velocity(row.blend_velocity));
+        /// The width in pixels, when it is `Fixed`.
+        WIDTH =>
+            pixels(row.width).map(|w| row.width_timing.eager(row.at, w, row.ease)),
+            Some(row.width_timing.lazy(row.at, 0., row.ease).with_velocity(row.blend_velocity));
+        /// The kind of width, `Fixed`, `Fill`, `FillPortion` or `Shrink`.
+        WIDTH_KIND =>
+            kind(row.width).map(|k| row.width_timing.step(row.at, k, row.ease)),
+            Some(row.width_timing.lazy_step(row.at, FIXED, row.ease));
+        /// The height in pixels, when it is `Fixed`.
+        HEIGHT =>
+            pixels(row.height).map(|h| row.height_timing.eager(row.at, h, row.ease)),
+            Some(row.height_timing.lazy(row.at, 0., row.ease).with_velocity(row.blend_velocity));
+        /// The kind of height, `Fixed`, `Fill`, `FillPortion` or `Shrink`.
+        HEIGHT_KIND =>
+            kind(row.height).map(|k| row.height_timing.step(row.at, k, row.ease)),
+            Some(row.height_timing.lazy_step(row.at, FIXED, row.ease));
     }
 }
@@ -1,39 +1,41 @@
-use crate::keyframes::{as_f32, get_length, Repeat};
-use crate::reexports::iced_core::{widget, Element, Length, Padding, Renderer as IcedRenderer};
+use crate::keyframes::{
+    get_appearance, get_color_space, get_length, gradient_angle, gradient_stops, kind, pixels,
+    solid_color, stop_value, timing_overrides, Timing, FIXED, STOP_COLUMNS,
+};
+use crate::reexports::iced_core::{
+    widget, Background, Color, Element, Length, Padding, Renderer as IcedRenderer,
+};
 use crate::reexports::ButtonStyleSheet;
 use crate::timeline::{Frame, Interped};
-use crate::{Ease, Linear, MovementType};
+use crate::{keyframe, properties, ColorSpace};
 
-/// A Button's animation Id. Used for linking animation built in `update()` with widget output in `view()`
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Id(widget::Id);
-
-impl Id {
-    /// Creates a custom [`Id`].
-    pub fn new(id: impl Into<std::borrow::Cow<'static, str>>) -> Self {
-        Self(widget::Id::new(id))
-    }
-
-    /// Creates a unique [`Id`].
-    ///
-    /// This function produces a different [`Id`] every time it is called.
-    #[must_use]
-    pub fn unique() -> Self {
-        Self(widget::Id::unique())
-    }
-
-    /// Used by [`crate::chain!`] macro
-    #[must_use]
-    pub fn into_chain(self) -> Chain {
-        Chain::new(self)
-    }
-
-    /// Used by [`crate::chain!`] macro
-    #[must_use]
-    pub fn into_chain_with_children(self, children: Vec<StyleButton>) -> Chain {
-        Chain::with_children(self, children)
+keyframe! {
+    #[derive(Debug, Clone, Copy)]
+    pub struct StyleButton {
+        width: Option<Length>,
+        height: Option<Length>,
+        padding: Option<Padding>,
+        style: Option<u8>,
+        background: Option<Background>,
+        text_color: Option<Color>,
+        border_color: Option<Color>,
+        border_width: Option<f32>,
+        border_radius: Option<f32>,
+        color_space: Option<ColorSpace>,
+        blend_velocity: bool,
+        width_timing: Timing,
+        height_timing: Timing,
+        padding_timing: Timing,
+        style_timing: Timing,
+        background_timing: Timing,
+        text_color_timing: Timing,
+        border_color_timing: Timing,
+        border_width_timing: Timing,
+        border_radius_timing: Timing,
     }
+}
 
+impl Id {
     /// Used by [`crate::anim!`] macro
     pub fn as_widget<'a, Message, Theme, Renderer>(
         self,
@@ -49,105 +51,7 @@ impl Id {
     }
 }
 
-impl From<Id> for widget::Id {
-    fn from(id: Id) -> Self {
-        id.0
-    }
-}
-
-#[derive(Debug)]
-pub struct Chain {
-    id: Id,
-    links: Vec<StyleButton>,
-    repeat: Repeat,
-}
-
-impl Chain {
-    pub fn new(id: Id) -> Self {
-        Chain {
-            id,
-            links: Vec::new(),
-            repeat: Repeat::Never,
-        }
-    }
-
-    pub fn with_children(id: Id, children: Vec<StyleButton>) -> Self {
-        Chain {
-            id,
-            links: children,
-            repeat: Repeat::Never,
-        }
-    }
-
-    pub fn link(mut self, button: StyleButton) -> Self {
-        self.links.push(button);
-        self
-    }
-
-    pub fn loop_forever(mut self) -> Self {
-        self.repeat = Repeat::Forever;
-        self
-    }
-
-    pub fn loop_once(mut self) -> Self {
-        self.repeat = Repeat::Never;
-        self
-    }
-}
-
-impl From<Chain> for crate::timeline::Chain {
-    fn from(chain: Chain) -> Self {
-        crate::timeline::Chain::new(
-            chain.id.into(),
-            chain.repeat,
-            chain
-                .links
-                .into_iter()
-                .map(std::convert::Into::into)
-                .collect::<Vec<_>>(),
-        )
-    }
-}
-
-#[must_use = "Keyframes are intended to be used in an animation chain."]
-#[derive(Debug, Clone, Copy)]
-pub struct StyleButton {
-    at: MovementType,
-    ease: Ease,
-    width: Option<Length>,
-    height: Option<Length>,
-    padding: Option<Padding>,
-    style: Option<u8>,
-    is_eager: bool,
-}
-
 impl StyleButton {
-    pub fn new(at: impl Into<MovementType>) -> StyleButton {
-        let at = at.into();
-        StyleButton {
-            at,
-            ease: Linear::InOut.into(),
-            width: None,
-            height: None,
-            padding: None,
-            style: None,
-            is_eager: true,
-        }
-    }
-
-    pub fn lazy(at: impl Into<MovementType>) -> StyleButton {
-        let at = at.into();
-        StyleButton {
-            at,
-            ease: Linear::InOut.into(),
-            width: None,
-            height: None,
-            padding: None,
-            style: None,
-            is_eager: false,
-        }
-    }
-
     // Returns a cosmic-time button, not a default iced button. The difference shouldn't
     // matter to the end user. Though it is an implementation detail.
     pub fn as_widget<'a, Message, Theme, Renderer>(
@@ -161,23 +65,53 @@ impl StyleButton {
         Theme: ButtonStyleSheet,
     {
         let id: widget::Id = id.into();
+        let space = get_color_space(&id, timeline, StyleButton::COLOR_SPACE);
 
         let button = crate::widget::Button::new(content)
-            .width(get_length(&id, timeline, 0, Length::Shrink))
-            .height(get_length(&id, timeline, 1, Length::Shrink))
+            .width(get_length(
+                &id,
+                timeline,
+                StyleButton::WIDTH,
+                StyleButton::WIDTH_KIND,
+                Length::Shrink,
+            ))
+            .height(get_length(
+                &id,
+                timeline,
+                StyleButton::HEIGHT,
+                StyleButton::HEIGHT_KIND,
+                Length::Shrink,
+            ))
             .padding([
-                timeline.get(&id, 2).map_or(5.0, |m| m.value),
-                timeline.get(&id, 3).map_or(5.0, |m| m.value),
-                timeline.get(&id, 4).map_or(5.0, |m| m.value),
-                timeline.get(&id, 5).map_or(5.0, |m| m.value),
-            ]);
+                timeline
+                    .get(&id, StyleButton::PADDING_TOP)
+                    .map_or(5.0, |m| m.value),
+                timeline
+                    .get(&id, StyleButton::PADDING_RIGHT)
+                    .map_or(5.0, |m| m.value),
+                timeline
+                    .get(&id, StyleButton::PADDING_BOTTOM)
+                    .map_or(5.0, |m| m.value),
+                timeline
+                    .get(&id, StyleButton::PADDING_LEFT)
+                    .map_or(5.0, |m| m.value),
+            ])
+            .override_appearance(get_appearance(
+                &id,
+                timeline,
+                StyleButton::BACKGROUND_R,
+                StyleButton::BACKGROUND_SET,
+                StyleButton::BACKGROUND_ANGLE,
+                space,
+            ))
+            .color_space(space);
 
         if let Some(Interped {
             previous,
             next,
             percent,
             ..
-        }) = timeline.get(&id, 6)
+        }) = timeline.get(&id, StyleButton::STYLE)
         {
             button.blend_style(style(previous as u8), style(next as u8), percent)
         } else {
@@ -200,8 +134,21 @@ impl StyleButton {
         self
     }
 
-    pub fn ease<E: Into<Ease>>(mut self, ease: E) -> Self {
-        self.ease = ease.into();
+    timing_overrides! {
+        width_timing: width_at, width_ease;
+        height_timing: height_at, height_ease;
+        padding_timing: padding_at, padding_ease;
+        style_timing: style_at, style_ease;
+        background_timing: background_at, background_ease;
+        text_color_timing: text_color_at, text_color_ease;
+        border_color_timing: border_color_at, border_color_ease;
+        border_width_timing: border_width_at, border_width_ease;
+        border_radius_timing: border_radius_at, border_radius_ease;
+    }
+
+    // does nothing if eager
+    pub fn blend_velocity(mut self) -> Self {
+        self.blend_velocity = true;
         self
     }
 
@@ -210,29 +157,169 @@ impl StyleButton {
         self.style = Some(style);
         self
     }
+
+    /// A solid color or a gradient. Gradients are blended stop by stop, and with
+    /// solid colors as a gradient of that one color.
+    pub fn background(mut self, background: impl Into<Background>) -> Self {
+        self.background = Some(background.into());
+        self
+    }
+
+    pub fn text_color(mut self, text_color: impl Into<Color>) -> Self {
+        self.text_color = Some(text_color.into());
+        self
+    }
+
+    pub fn border_color(mut self, border_color: impl Into<Color>) -> Self {
+        self.border_color = Some(border_color.into());
+        self
+    }
+
+    pub fn border_width(mut self, border_width: f32) -> Self {
+        self.border_width = Some(border_width);
+        self
+    }
+
+    pub fn border_radius(mut self, border_radius: f32) -> Self {
+        self.border_radius = Some(border_radius);
+        self
+    }
+
+    pub fn color_space(mut self, color_space: ColorSpace) -> Self {
+        self.color_space = Some(color_space);
+        self
+    }
 }
 
-#[rustfmt::skip]
-impl From<StyleButton> for Vec<Option<Frame>> {
-    fn from(button: StyleButton) -> Vec<Option<Frame>> {
-      if button.is_eager {
-        vec![as_f32(button.width).map(|w| Frame::eager(button.at, w, button.ease)),  // 0 = width
-             as_f32(button.height).map(|h| Frame::eager(button.at, h, button.ease)), // 1 = height
-             button.padding.map(|p| Frame::eager(button.at, p.top, button.ease)),    // 2 = padding[0] (top)
-             button.padding.map(|p| Frame::eager(button.at, p.right, button.ease)),  // 3 = padding[1] (right)
-             button.padding.map(|p| Frame::eager(button.at, p.bottom, button.ease)), // 4 = padding[2] (bottom)
-             button.padding.map(|p| Frame::eager(button.at, p.left, button.ease)),  // 5 = padding[3] (left)
-             button.style.map(|s| Frame::eager(button.at, f32::from(s), button.ease)),  // 6 = style blend (passed to widget to mix values at `draw` time)
-        ]
-      } else {
-        vec![Some(Frame::lazy(button.at, 0., button.ease)), // 0 = width
-             Some(Frame::lazy(button.at, 0., button.ease)), // 1 = height
-             Some(Frame::lazy(button.at, 5., button.ease)), // 2 = padding[0] (top)
-             Some(Frame::lazy(button.at, 5., button.ease)), // 3 = padding[1] (right)
-             Some(Frame::lazy(button.at, 5., button.ease)), // 4 = padding[2] (bottom)
-             Some(Frame::lazy(button.at, 5., button.ease)), // 5 = padding[3] (left)
-             Some(Frame::lazy(button.at, 0., button.ease)), // 6 = style blend (passed to widget to mix values at `draw` time)
-        ]
-      }
+properties! {
+    StyleButton(button) {
+        /// The width in pixels, when it is `Fixed`.
+        WIDTH =>
+            pixels(button.width).map(|w| button.width_timing.eager(button.at, w, button.ease)),
+            Some(button.width_timing.lazy(button.at, 0., button.ease).with_velocity(button.blend_velocity));
+        /// The kind of width, `Fixed`, `Fill`, `FillPortion` or `Shrink`.
+        WIDTH_KIND =>
+            kind(button.width).map(|k| button.width_timing.step(button.at, k, button.ease)),
+            Some(button.width_timing.lazy_step(button.at, FIXED, button.ease));
+        /// The height in pixels, when it is `Fixed`.
+        HEIGHT =>
+            pixels(button.height).map(|h| button.height_timing.eager(button.at, h, button.ease)),
+            Some(button.height_timing.lazy(button.at, 0., button.ease).with_velocity(button.blend_velocity));
+        /// The kind of height, `Fixed`, `Fill`, `FillPortion` or `Shrink`.
+        HEIGHT_KIND =>
+            kind(button.height).map(|k| button.height_timing.step(button.at, k, button.ease)),
+            Some(button.height_timing.lazy_step(button.at, FIXED, button.ease));
+        /// The top padding.
+        PADDING_TOP =>
+            button.padding.map(|p| button.padding_timing.eager(button.at, p.top, button.ease)),
+            Some(button.padding_timing.lazy(button.at, 5., button.ease).with_velocity(button.blend_velocity));
+        /// The right padding.
+        PADDING_RIGHT =>
+            button.padding.map(|p| button.padding_timing.eager(button.at, p.right, button.ease)),
+            Some(button.padding_timing.lazy(button.at, 5., button.ease).with_velocity(button.blend_velocity));
+        /// The bottom padding.
+        PADDING_BOTTOM =>
+            button.padding.map(|p| button.padding_timing.eager(button.at, p.bottom, button.ease)),
+            Some(button.padding_timing.lazy(button.at, 5., button.ease).with_velocity(button.blend_velocity));
+        /// The left padding.
+        PADDING_LEFT =>
+            button.padding.map(|p| button.padding_timing.eager(button.at, p.left, button.ease)),
+            Some(button.padding_timing.lazy(button.at, 5., button.ease).with_velocity(button.blend_velocity));
+        /// The style blend, passed to the widget to mix values at `draw` time.
+        STYLE =>
+            button.style.map(|s| button.style_timing.eager(button.at, f32::from(s), button.ease)),
+            Some(button.style_timing.lazy(button.at, 0., button.ease).with_velocity(button.blend_velocity));
+        /// The red channel of the background, or of a gradient's first stop.
+        BACKGROUND_R =>
+            button.background.map(solid_color).map(|c| button.background_timing.eager(button.at, c.r, button.ease)),
+            Some(button.background_timing.lazy(button.at, 0., button.ease).with_velocity(button.blend_velocity));
+        /// The green channel of the background, or of a gradient's first stop.
+        BACKGROUND_G =>
+            button.background.map(solid_color).map(|c| button.background_timing.eager(button.at, c.g, button.ease)),
+            Some(button.background_timing.lazy(button.at, 0., button.ease).with_velocity(button.blend_velocity));
+        /// The blue channel of the background, or of a gradient's first stop.
+        BACKGROUND_B =>
+            button.background.map(solid_color).map(|c| button.background_timing.eager(button.at, c.b, button.ease)),
+            Some(button.background_timing.lazy(button.at, 0., button.ease).with_velocity(button.blend_velocity));
+        /// The alpha channel of the background, or of a gradient's first stop.
+        BACKGROUND_A =>
+            button.background.map(solid_color).map(|c| button.background_timing.eager(button.at, c.a, button.ease)),
+            Some(button.background_timing.lazy(button.at, 0., button.ease).with_velocity(button.blend_velocity));
+        /// The red channel of the text color.
+        TEXT_COLOR_R =>
+            button.text_color.map(|c| button.text_color_timing.eager(button.at, c.r, button.ease)),
+            Some(button.text_color_timing.lazy(button.at, 0., button.ease).with_velocity(button.blend_velocity));
+        /// The green channel of the text color.
+        TEXT_COLOR_G =>
+            button.text_color.map(|c| button.text_color_timing.eager(button.at, c.g, button.ease)),
+            Some(button.text_color_timing.lazy(button.at, 0., button.ease).with_velocity(button.blend_velocity));
+        /// The blue channel of the text color.
+        TEXT_COLOR_B =>
+            button.text_color.map(|c| button.text_color_timing.eager(button.at, c.b, button.ease)),
+            Some(button.text_color_timing.lazy(button.at, 0., button.ease).with_velocity(button.blend_velocity));
+        /// The alpha channel of the text color.
+        TEXT_COLOR_A =>
+            button.text_color.map(|c| button.text_color_timing.eager(button.at, c.a, button.ease)),
+            Some(button.text_color_timing.lazy(button.at, 0., button.ease).with_velocity(button.blend_velocity));
+        /// The red channel of the border color.
+        BORDER_COLOR_R =>
+            button.border_color.map(|c| button.border_color_timing.eager(button.at, c.r, button.ease)),
+            Some(button.border_color_timing.lazy(button.at, 0., button.ease).with_velocity(button.blend_velocity));
+        /// The green channel of the border color.
+        BORDER_COLOR_G =>
+            button.border_color.map(|c| button.border_color_timing.eager(button.at, c.g, button.ease)),
+            Some(button.border_color_timing.lazy(button.at, 0., button.ease).with_velocity(button.blend_velocity));
+        /// The blue channel of the border color.
+        BORDER_COLOR_B =>
+            button.border_color.map(|c| button.border_color_timing.eager(button.at, c.b, button.ease)),
+            Some(button.border_color_timing.lazy(button.at, 0., button.ease).with_velocity(button.blend_velocity));
+        /// The alpha channel of the border color.
+        BORDER_COLOR_A =>
+            button.border_color.map(|c| button.border_color_timing.eager(button.at, c.a, button.ease)),
+            Some(button.border_color_timing.lazy(button.at, 0., button.ease).with_velocity(button.blend_velocity));
+        /// The border width.
+        BORDER_WIDTH =>
+            button.border_width.map(|w| button.border_width_timing.eager(button.at, w, button.ease)),
+            Some(button.border_width_timing.lazy(button.at, 0., button.ease).with_velocity(button.blend_velocity));
+        /// The border radius.
+        BORDER_RADIUS =>
+            button.border_radius.map(|r| button.border_radius_timing.eager(button.at, r, button.ease)),
+            Some(button.border_radius_timing.lazy(button.at, 0., button.ease).with_velocity(button.blend_velocity));
+        /// The color space colors are blended in. A step, so it is never blended itself.
+        COLOR_SPACE =>
+            button.color_space.map(|s| Frame::step(button.at, s.as_f32(), button.ease)),
+            None;
+        /// Whether the background is set, `1.` if it is. A step, so it is never blended.
+        BACKGROUND_SET =>
+            button.background.map(|_| button.background_timing.step(button.at, 1., button.ease)),
+            Some(button.background_timing.lazy_step(button.at, 0., button.ease));
+        /// Whether the text color is set, `1.` if it is. A step, so it is never blended.
+        TEXT_COLOR_SET =>
+            button.text_color.map(|_| button.text_color_timing.step(button.at, 1., button.ease)),
+            Some(button.text_color_timing.lazy_step(button.at, 0., button.ease));
+        /// Whether the border color is set, `1.` if it is. A step, so it is never blended.
+        BORDER_COLOR_SET =>
+            button.border_color.map(|_| button.border_color_timing.step(button.at, 1., button.ease)),
+            Some(button.border_color_timing.lazy_step(button.at, 0., button.ease));
+        /// Whether the border width is set, `1.` if it is. A step, so it is never blended.
+        BORDER_WIDTH_SET =>
+            button.border_width.map(|_| button.border_width_timing.step(button.at, 1., button.ease)),
+            Some(button.border_width_timing.lazy_step(button.at, 0., button.ease));
+        /// Whether the border radius is set, `1.` if it is. A step, so it is never blended.
+        BORDER_RADIUS_SET =>
+            button.border_radius.map(|_| button.border_radius_timing.step(button.at, 1., button.ease)),
+            Some(button.border_radius_timing.lazy_step(button.at, 0., button.ease));
+        /// The angle of a gradient background, in radians.
+        BACKGROUND_ANGLE =>
+            button.background.map(|b| button.background_timing.eager(button.at, gradient_angle(b), button.ease)),
+            Some(button.background_timing.lazy(button.at, 0., button.ease).with_velocity(button.blend_velocity));
+        /// The number of stops of a gradient background, `0.` for a solid color. A step, so it is never blended.
+        BACKGROUND_STOPS =>
+            button.background.map(|b| button.background_timing.step(button.at, gradient_stops(b), button.ease)),
+            Some(button.background_timing.lazy_step(button.at, 0., button.ease));
+        /// The offset, red, green, blue and alpha of each stop of a gradient background, 8 stops in all.
+        BACKGROUND_STOP_VALUES[STOP_COLUMNS] =>
+            (0..STOP_COLUMNS).map(|i| button.background.map(|b| button.background_timing.eager(button.at, stop_value(b, i), button.ease))),
+            (0..STOP_COLUMNS).map(|_| Some(button.background_timing.lazy(button.at, 0., button.ease).with_velocity(button.blend_velocity)));
     }
 }
@@ -1,42 +1,47 @@
 use crate::reexports::iced_core::{
-    widget::Id as IcedId, Element, Length, Padding, Pixels, Renderer as IcedRenderer,
+    widget::Id as IcedId, Background, Color, Element, Length, Padding, Pixels,
+    Renderer as IcedRenderer,
 };
 use crate::reexports::iced_style::container::StyleSheet;
 
-use crate::keyframes::{as_f32, get_length, Repeat};
+use crate::keyframes::{
+    get_appearance, get_color_space, get_length, gradient_angle, gradient_stops, kind, pixels,
+    solid_color, stop_value, timing_overrides, Timing, FIXED, STOP_COLUMNS,
+};
 use crate::timeline::{Frame, Interped};
-use crate::{Ease, Linear, MovementType};
-
-/// A `StyleContainer`'s animation Id. Used for linking animation built in `update()` with widget output in `view()`
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Id(IcedId);
-
-impl Id {
-    /// Creates a custom [`Id`].
-    pub fn new(id: impl Into<std::borrow::Cow<'static, str>>) -> Self {
-        Self(IcedId::new(id))
-    }
-
-    /// Creates a unique [`Id`].
-    ///
-    /// This function produces a different [`Id`] every time it is called.
-    #[must_use]
-    pub fn unique() -> Self {
-        Self(IcedId::unique())
-    }
-
-    /// Used by [`crate::chain!`] macro
-    #[must_use]
-    pub fn into_chain(self) -> Chain {
-        Chain::new(self)
-    }
-
-    /// Used by [`crate::chain!`] macro
-    #[must_use]
-    pub fn into_chain_with_children(self, children: Vec<StyleContainer>) -> Chain {
-        Chain::with_children(self, children)
+use crate::{keyframe, properties, ColorSpace};
+
+keyframe! {
+    #[derive(Debug, Clone, Copy)]
+    pub struct StyleContainer {
+        width: Option<Length>,
+        height: Option<Length>,
+        padding: Option<Padding>,
+        max_width: Option<f32>,
+        max_height: Option<f32>,
+        style: Option<u8>,
+        background: Option<Background>,
+        text_color: Option<Color>,
+        border_color: Option<Color>,
+        border_width: Option<f32>,
+        border_radius: Option<f32>,
+        color_space: Option<ColorSpace>,
+        blend_velocity: bool,
+        width_timing: Timing,
+        height_timing: Timing,
+        padding_timing: Timing,
+        max_width_timing: Timing,
+        max_height_timing: Timing,
+        style_timing: Timing,
+        background_timing: Timing,
+        text_color_timing: Timing,
+        border_color_timing: Timing,
+        border_width_timing: Timing,
+        border_radius_timing: Timing,
     }
+}
 
+impl Id {
     #[cfg(feature = "iced")]
     /// Used by [`crate::anim!`] macro
     pub fn as_widget<'a, Message, Theme, Renderer>(
@@ -70,111 +75,7 @@ impl Id {
     }
 }
 
-impl From<Id> for IcedId {
-    fn from(id: Id) -> Self {
-        id.0
-    }
-}
-
-#[derive(Debug)]
-pub struct Chain {
-    id: Id,
-    links: Vec<StyleContainer>,
-    repeat: Repeat,
-}
-
-impl Chain {
-    pub fn new(id: Id) -> Self {
-        Chain {
-            id,
-            links: Vec::new(),
-            repeat: Repeat::Never,
-        }
-    }
-
-    pub fn with_children(id: Id, children: Vec<StyleContainer>) -> Self {
-        Chain {
-            id,
-            links: children,
-            repeat: Repeat::Never,
-        }
-    }
-
-    pub fn link(mut self, container: StyleContainer) -> Self {
-        self.links.push(container);
-        self
-    }
-
-    pub fn loop_forever(mut self) -> Self {
-        self.repeat = Repeat::Forever;
-        self
-    }
-
-    pub fn loop_once(mut self) -> Self {
-        self.repeat = Repeat::Never;
-        self
-    }
-}
-
-impl From<Chain> for crate::timeline::Chain {
-    fn from(chain: Chain) -> Self {
-        crate::timeline::Chain::new(
-            chain.id.into(),
-            chain.repeat,
-            chain
-                .links
-                .into_iter()
-                .map(std::convert::Into::into)
-                .collect::<Vec<_>>(),
-        )
-    }
-}
-
-#[must_use = "Keyframes are intended to be used in an animation chain."]
-#[derive(Debug, Clone, Copy)]
-pub struct StyleContainer {
-    at: MovementType,
-    ease: Ease,
-    width: Option<Length>,
-    height: Option<Length>,
-    padding: Option<Padding>,
-    max_width: Option<f32>,
-    max_height: Option<f32>,
-    style: Option<u8>,
-    is_eager: bool,
-}
-
 impl StyleContainer {
-    pub fn new(at: impl Into<MovementType>) -> StyleContainer {
-        let at = at.into();
-        StyleContainer {
-            at,
-            ease: Linear::InOut.into(),
-            width: None,
-            height: None,
-            padding: None,
-            max_width: None,
-            max_height: None,
-            style: None,
-            is_eager: true,
-        }
-    }
-
-    pub fn lazy(at: impl Into<MovementType>) -> StyleContainer {
-        let at = at.into();
-        StyleContainer {
-            at,
-            ease: Linear::InOut.into(),
-            width: None,
-            height: None,
-            padding: None,
-            max_width: None,
-            max_height: None,
-            style: None,
-            is_eager: false,
-        }
-    }
-
     // Returns a cosmic-time container, not a default iced button. The difference shouldn't
     // matter to the end user. Though it is an implementation detail.
     #[cfg(feature = "iced")]
@@ -189,25 +90,63 @@ impl StyleContainer {
         Theme: StyleSheet,
     {
         let id: IcedId = id.into();
+        let space = get_color_space(&id, timeline, StyleContainer::COLOR_SPACE);
 
         let container = crate::widget::Container::new(content)
-            .width(get_length(&id, timeline, 0, Length::Shrink))
-            .height(get_length(&id, timeline, 1, Length::Shrink))
+            .width(get_length(
+                &id,
+                timeline,
+                StyleContainer::WIDTH,
+                StyleContainer::WIDTH_KIND,
+                Length::Shrink,
+            ))
+            .height(get_length(
+                &id,
+                timeline,
+                StyleContainer::HEIGHT,
+                StyleContainer::HEIGHT_KIND,
+                Length::Shrink,
+            ))
             .padding([
-                timeline.get(&id, 2).map_or(0., |m| m.value),
-                timeline.get(&id, 3).map_or(0., |m| m.value),
-                timeline.get(&id, 4).map_or(0., |m| m.value),
-                timeline.get(&id, 5).map_or(0., |m| m.value),
+                timeline
+                    .get(&id, StyleContainer::PADDING_TOP)
+                    .map_or(0., |m| m.value),
+                timeline
+                    .get(&id, StyleContainer::PADDING_RIGHT)
+                    .map_or(0., |m| m.value),
+                timeline
+                    .get(&id, StyleContainer::PADDING_BOTTOM)
+                    .map_or(0., |m| m.value),
+                timeline
+                    .get(&id, StyleContainer::PADDING_LEFT)
+                    .map_or(0., |m| m.value),
             ])
-            .max_width(timeline.get(&id, 6).map_or(f32::INFINITY, |m| m.value))
-            .max_height(timeline.get(&id, 7).map_or(f32::INFINITY, |m| m.value));
+            .max_width(
+                timeline
+                    .get(&id, StyleContainer::MAX_WIDTH)
+                    .map_or(f32::INFINITY, |m| m.value),
+            )
+            .max_height(
+                timeline
+                    .get(&id, StyleContainer::MAX_HEIGHT)
+                    .map_or(f32::INFINITY, |m| m.value),
+            )
+            .override_appearance(get_appearance(
+                &id,
+                timeline,
+                StyleContainer::BACKGROUND_R,
+                StyleContainer::BACKGROUND_SET,
+                StyleContainer::BACKGROUND_ANGLE,
+                space,
+            ))
+            .color_space(space);
 
         if let Some(Interped {
             previous,
             next,
             percent,
             ..
-        }) = timeline.get(&id, 8)
+        }) = timeline.get(&id, StyleContainer::STYLE)
         {
             container.blend_style(style(previous as u8), style(next as u8), percent)
         } else {
@@ -229,25 +168,63 @@ impl StyleContainer {
             From<cosmic::theme::Container>,
     {
         let id: IcedId = id.into();
+        let space = get_color_space(&id, timeline, StyleContainer::COLOR_SPACE);
 
         let container = crate::widget::Container::new(content)
-            .width(get_length(&id, timeline, 0, Length::Shrink))
-            .height(get_length(&id, timeline, 1, Length::Shrink))
+            .width(get_length(
+                &id,
+                timeline,
+                StyleContainer::WIDTH,
+                StyleContainer::WIDTH_KIND,
+                Length::Shrink,
+            ))
+            .height(get_length(
+                &id,
+                timeline,
+                StyleContainer::HEIGHT,
+                StyleContainer::HEIGHT_KIND,
+                Length::Shrink,
+            ))
             .padding([
-                timeline.get(&id, 2).map_or(0., |m| m.value),
-                timeline.get(&id, 3).map_or(0., |m| m.value),
-                timeline.get(&id, 4).map_or(0., |m| m.value),
-                timeline.get(&id, 5).map_or(0., |m| m.value),
+                timeline
+                    .get(&id, StyleContainer::PADDING_TOP)
+                    .map_or(0., |m| m.value),
+                timeline
+                    .get(&id, StyleContainer::PADDING_RIGHT)
+                    .map_or(0., |m| m.value),
+                timeline
+                    .get(&id, StyleContainer::PADDING_BOTTOM)
+                    .map_or(0., |m| m.value),
+                timeline
+                    .get(&id, StyleContainer::PADDING_LEFT)
+                    .map_or(0., |m| m.value),
             ])
-            .max_width(timeline.get(&id, 6).map_or(f32::INFINITY, |m| m.value))
-            .max_height(timeline.get(&id, 7).map_or(f32::INFINITY, |m| m.value));
+            .max_width(
+                timeline
+                    .get(&id, StyleContainer::MAX_WIDTH)
+                    .map_or(f32::INFINITY, |m| m.value),
+            )
+            .max_height(
+                timeline
+                    .get(&id, StyleContainer::MAX_HEIGHT)
+                    .map_or(f32::INFINITY, |m| m.value),
+            )
+            .override_appearance(get_appearance(
+                &id,
+                timeline,
+                StyleContainer::BACKGROUND_R,
+                StyleContainer::BACKGROUND_SET,
+                StyleContainer::BACKGROUND_ANGLE,
+                space,
+            ))
+            .color_space(space);
 
         if let Some(Interped {
             previous,
             next,
             percent,
             ..
-        }) = timeline.get(&id, 8)
+        }) = timeline.get(&id, StyleContainer::STYLE)
         {
             container.blend_style(style(previous as u8), style(next as u8), percent)
         } else {
@@ -280,8 +257,23 @@ impl StyleContainer {
         self
     }
 
-    pub fn ease<E: Into<Ease>>(mut self, ease: E) -> Self {
-        self.ease = ease.into();
+    timing_overrides! {
+        width_timing: width_at, width_ease;
+        height_timing: height_at, height_ease;
+        padding_timing: padding_at, padding_ease;
+        max_width_timing: max_width_at, max_width_ease;
+        max_height_timing: max_height_at, max_height_ease;
+        style_timing: style_at, style_ease;
+        background_timing: background_at, background_ease;
+        text_color_timing: text_color_at, text_color_ease;
+        border_color_timing: border_color_at, border_color_ease;
+        border_width_timing: border_width_at, border_width_ease;
+        border_radius_timing: border_radius_at, border_radius_ease;
+    }
+
+    // does nothing if eager
+    pub fn blend_velocity(mut self) -> Self {
+        self.blend_velocity = true;
         self
     }
 
@@ -290,24 +282,177 @@ impl StyleContainer {
         self.style = Some(style);
         self
     }
+
+    /// A solid color or a gradient. Gradients are blended stop by stop, and with
+    /// solid colors as a gradient of that one color.
+    pub fn background(mut self, background: impl Into<Background>) -> Self {
+        self.background = Some(background.into());
+        self
+    }
+
+    pub fn text_color(mut self, text_color: impl Into<Color>) -> Self {
+        self.text_color = Some(text_color.into());
+        self
+    }
+
+    pub fn border_color(mut self, border_color: impl Into<Color>) -> Self {
+        self.border_color = Some(border_color.into());
+        self
+    }
+
+    pub fn border_width(mut self, border_width: f32) -> Self {
+        self.border_width = Some(border_width);
+        self
+    }
+
+    pub fn border_radius(mut self, border_radius: f32) -> Self {
+        self.border_radius = Some(border_radius);
+        self
+    }
+
+    pub fn color_space(mut self, color_space: ColorSpace) -> Self {
+        self.color_space = Some(color_space);
+        self
+    }
 }
 
-#[rustfmt::skip]
-impl From<StyleContainer> for Vec<Option<Frame>> {
-    fn from(container: StyleContainer) -> Vec<Option<Frame>> {
-      if container.is_eager {
-        vec![as_f32(container.width).map(|w| Frame::eager(container.at, w, container.ease)),  // 0 = width
-             as_f32(container.height).map(|h| Frame::eager(container.at, h, container.ease)), // 1 = height
-             container.padding.map(|p| Frame::eager(container.at, p.top, container.ease)),    // 2 = padding[0] (top)
-             container.padding.map(|p| Frame::eager(container.at, p.right, container.ease)),  // 3 = padding[1] (right)
-             container.padding.map(|p| Frame::eager(container.at, p.bottom, container.ease)), // 4 = padding[2] (bottom)
-             container.padding.map(|p| Frame::eager(container.at, p.left, container.ease)),   // 5 = padding[3] (left)
-             container.max_width.map(|w| Frame::eager(container.at, w, container.ease)),      // 6 = max_width
-             container.max_height.map(|h| Frame::eager(container.at, h, container.ease)),     // 7 = max_height
-             container.style.map(|s| Frame::eager(container.at, f32::from(s), container.ease)),   // 6 = style blend (passed to widget to mix values at `draw` time)
-        ]
-      } else {
-        vec![Some(Frame::lazy(container.at, 0., container.ease)); 9] // lazy evaluates for all values
-      }
+properties! {
+    StyleContainer(container) {
+        /// The width in pixels, when it is `Fixed`.
+        WIDTH =>
+            pixels(container.width).map(|w| container.width_timing.eager(container.at, w, container.ease)),
+            Some(container.width_timing.lazy(container.at, 0., container.ease).with_velocity(container.blend_velocity));
+        /// The kind of width, `Fixed`, `Fill`, `FillPortion` or `Shrink`.
+        WIDTH_KIND =>
+            kind(container.width).map(|k| container.width_timing.step(container.at, k, container.ease)),
+            Some(container.width_timing.lazy_step(container.at, FIXED, container.ease));
+        /// The height in pixels, when it is `Fixed`.
+        HEIGHT =>
+            pixels(container.height).map(|h| container.height_timing.eager(container.at, h, container.ease)),
+            Some(container.height_timing.lazy(container.at, 0., container.ease).with_velocity(container.blend_velocity));
+        /// The kind of height, `Fixed`, `Fill`, `FillPortion` or `Shrink`.
+        HEIGHT_KIND =>
+            kind(container.height).map(|k| container.height_timing.step(container.at, k, container.ease)),
+            Some(container.height_timing.lazy_step(container.at, FIXED, container.ease));
+        /// The top padding.
+        PADDING_TOP =>
+            container.padding.map(|p| container.padding_timing.eager(container.at, p.top, container.ease)),
+            Some(container.padding_timing.lazy(container.at, 0., container.ease).with_velocity(container.blend_velocity));
+        /// The right padding.
+        PADDING_RIGHT =>
+            container.padding.map(|p| container.padding_timing.eager(container.at, p.right, container.ease)),
+            Some(container.padding_timing.lazy(container.at, 0., container.ease).with_velocity(container.blend_velocity));
+        /// The bottom padding.
+        PADDING_BOTTOM =>
+            container.padding.map(|p| container.padding_timing.eager(container.at, p.bottom, container.ease)),
+            Some(container.padding_timing.lazy(container.at, 0., container.ease).with_velocity(container.blend_velocity));
+        /// The left padding.
+        PADDING_LEFT =>
+            container.padding.map(|p| container.padding_timing.eager(container.at, p.left, container.ease)),
+            Some(container.padding_timing.lazy(container.at, 0., container.ease).with_velocity(container.blend_velocity));
+        /// The maximum width.
+        MAX_WIDTH =>
+            container.max_width.map(|w| container.max_width_timing.eager(container.at, w, container.ease)),
+            Some(container.max_width_timing.lazy(container.at, 0., container.ease).with_velocity(container.blend_velocity));
+        /// The maximum height.
+        MAX_HEIGHT =>
+            container.max_height.map(|h| container.max_height_timing.eager(container.at, h, container.ease)),
+            Some(container.max_height_timing.lazy(container.at, 0., container.ease).with_velocity(container.blend_velocity));
+        /// The style blend, passed to the widget to mix values at `draw` time.
+        STYLE =>
+            container.style.map(|s| container.style_timing.eager(container.at, f32::from(s), container.ease)),
+            Some(container.style_timing.lazy(container.at, 0., container.ease).with_velocity(container.blend_velocity));
+        /// The red channel of the background, or of a gradient's first stop.
+        BACKGROUND_R =>
+            container.background.map(solid_color).map(|c| container.background_timing.eager(container.at, c.r, container.ease)),
+            Some(container.background_timing.lazy(container.at, 0., container.ease).with_velocity(container.blend_velocity));
+        /// The green channel of the background, or of a gradient's first stop.
+        BACKGROUND_G =>
+            container.background.map(solid_color).map(|c| container.background_timing.eager(container.at, c.g, container.ease)),
+            Some(container.background_timing.lazy(container.at, 0., container.ease).with_velocity(container.blend_velocity));
+        /// The blue channel of the background, or of a gradient's first stop.
+        BACKGROUND_B =>
+            container.background.map(solid_color).map(|c| container.background_timing.eager(container.at, c.b, container.ease)),
+            Some(container.background_timing.lazy(container.at, 0., container.ease).with_velocity(container.blend_velocity));
+        /// The alpha channel of the background, or of a gradient's first stop.
+        BACKGROUND_A =>
+            container.background.map(solid_color).map(|c| container.background_timing.eager(container.at, c.a, container.ease)),
+            Some(container.background_timing.lazy(container.at, 0., container.ease).with_velocity(container.blend_velocity));
+        /// The red channel of the text color.
+        TEXT_COLOR_R =>
+            container.text_color.map(|c| container.text_color_timing.eager(container.at, c.r, container.ease)),
+            Some(container.text_color_timing.lazy(container.at, 0., container.ease).with_velocity(container.blend_velocity));
+        /// The green channel of the text color.
+        TEXT_COLOR_G =>
+            container.text_color.map(|c| container.text_color_timing.eager(container.at, c.g, container.ease)),
+            Some(container.text_color_timing.lazy(container.at, 0., container.ease).with_velocity(container.blend_velocity));
+        /// The blue channel of the text color.
+        TEXT_COLOR_B =>
+            container.text_color.map(|c| container.text_color_timing.eager(container.at, c.b, container.ease)),
+            Some(container.text_color_timing.lazy(container.at, 0., container.ease).with_velocity(container.blend_velocity));
+        /// The alpha channel of the text color.
+        TEXT_COLOR_A =>
+            container.text_color.map(|c| container.text_color_timing.eager(container.at, c.a, container.ease)),
+            Some(container.text_color_timing.lazy(container.at, 0., container.ease).with_velocity(container.blend_velocity));
+        /// The red channel of the border color.
+        BORDER_COLOR_R =>
+            container.border_color.map(|c| container.border_color_timing.eager(container.at, c.r, container.ease)),
+            Some(container.border_color_timing.lazy(container.at, 0., container.ease).with_velocity(container.blend_velocity));
+        /// The green channel of the border color.
+        BORDER_COLOR_G =>
+            container.border_color.map(|c| container.border_color_timing.eager(container.at, c.g, container.ease)),
+            Some(container.border_color_timing.lazy(container.at, 0., container.ease).with_velocity(container.blend_velocity));
+        /// The blue channel of the border color.
+        BORDER_COLOR_B =>
+            container.border_color.map(|c| container.border_color_timing.eager(container.at, c.b, container.ease)),
+            Some(container.border_color_timing.lazy(container.at, 0., container.ease).with_velocity(container.blend_velocity));
+        /// The alpha channel of the border color.
+        BORDER_COLOR_A =>
+            container.border_color.map(|c| container.border_color_timing.eager(container.at, c.a, container.ease)),
+            Some(container.border_color_timing.lazy(container.at, 0., container.ease).with_velocity(container.blend_velocity));
+        /// The border width.
+        BORDER_WIDTH =>
+            container.border_width.map(|w| container.border_width_timing.eager(container.at, w, container.ease)),
+            Some(container.border_width_timing.lazy(container.at, 0., container.ease).with_velocity(container.blend_velocity));
+        /// The border radius.
+        BORDER_RADIUS =>
+            container.border_radius.map(|r| container.border_radius_timing.eager(container.at, r, container.ease)),
+            Some(container.border_radius_timing.lazy(container.at, 0., container.ease).with_velocity(container.blend_velocity));
+        /// The color space colors are blended in. A step, so it is never blended itself.
+        COLOR_SPACE =>
+            container.color_space.map(|s| Frame::step(container.at, s.as_f32(), container.ease)),
+            None;
+        /// Whether the background is set, `1.` if it is. A step, so it is never blended.
+        BACKGROUND_SET =>
+            container.background.map(|_| container.background_timing.step(container.at, 1., container.ease)),
+            Some(container.background_timing.lazy_step(container.at, 0., container.ease));
+        /// Whether the text color is set, `1.` if it is. A step, so it is never blended.
+        TEXT_COLOR_SET =>
+            container.text_color.map(|_| container.text_color_timing.step(container.at, 1., container.ease)),
+            Some(container.text_color_timing.lazy_step(container.at, 0., container.ease));
+        /// Whether the border color is set, `1.` if it is. A step, so it is never blended.
+        BORDER_COLOR_SET =>
+            container.border_color.map(|_| container.border_color_timing.step(container.at, 1., container.ease)),
+            Some(container.border_color_timing.lazy_step(container.at, 0., container.ease));
+        /// Whether the border width is set, `1.` if it is. A step, so it is never blended.
+        BORDER_WIDTH_SET =>
+            container.border_width.map(|_| container.border_width_timing.step(container.at, 1., container.ease)),
+            Some(container.border_width_timing.lazy_step(container.at, 0., container.ease));
+        /// Whether the border radius is set, `1.` if it is. A step, so it is never blended.
+        BORDER_RADIUS_SET =>
+            container.border_radius.map(|_| container.border_radius_timing.step(container.at, 1., container.ease)),
+            Some(container.border_radius_timing.lazy_step(container.at, 0., container.ease));
+        /// The angle of a gradient background, in radians.
+        BACKGROUND_ANGLE =>
+            container.background.map(|b| container.background_timing.eager(container.at, gradient_angle(b), container.ease)),
+            Some(container.background_timing.lazy(container.at, 0., container.ease).with_velocity(container.blend_velocity));
+        /// The number of stops of a gradient background, `0.` for a solid color. A step, so it is never blended.
+        BACKGROUND_STOPS =>
+            container.background.map(|b| container.background_timing.step(container.at, gradient_stops(b), container.ease)),
+            Some(container.background_timing.lazy_step(container.at, 0., container.ease));
+        /// The offset, red, green, blue and alpha of each stop of a gradient background, 8 stops in all.
+        BACKGROUND_STOP_VALUES[STOP_COLUMNS] =>
+            (0..STOP_COLUMNS).map(|i| container.background.map(|b| container.background_timing.eager(container.at, stop_value(b, i), container.ease))),
+            (0..STOP_COLUMNS).map(|_| Some(container.background_timing.lazy(container.at, 0., container.ease).with_velocity(container.blend_velocity)));
     }
 }
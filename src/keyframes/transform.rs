@@ -0,0 +1,111 @@
+use crate::reexports::iced_core::{
+    widget::Id as IcedId, Element, Renderer as IcedRenderer, Vector,
+};
+
+use crate::keyframes::{timing_overrides, Timing};
+use crate::{keyframe, properties};
+
+keyframe! {
+    #[derive(Debug, Clone, Copy)]
+    pub struct Transform {
+        x: Option<f32>,
+        y: Option<f32>,
+        scale: Option<f32>,
+        blend_velocity: bool,
+        x_timing: Timing,
+        y_timing: Timing,
+        scale_timing: Timing,
+    }
+}
+
+impl Id {
+    /// Used by [`crate::anim!`] macro
+    #[must_use]
+    pub fn as_widget<'a, Message, Theme, Renderer>(
+        self,
+        timeline: &crate::Timeline,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> crate::widget::Transform<'a, Message, Theme, Renderer>
+    where
+        Renderer: IcedRenderer,
+    {
+        Transform::as_widget(self, timeline, content)
+    }
+}
+
+impl Transform {
+    pub fn as_widget<'a, Message, Theme, Renderer>(
+        id: Id,
+        timeline: &crate::Timeline,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> crate::widget::Transform<'a, Message, Theme, Renderer>
+    where
+        Renderer: IcedRenderer,
+    {
+        let id: IcedId = id.into();
+
+        crate::widget::Transform::new(content)
+            .translate(Vector::new(
+                timeline.get(&id, Transform::X).map_or(0., |m| m.value),
+                timeline.get(&id, Transform::Y).map_or(0., |m| m.value),
+            ))
+            .scale(timeline.get(&id, Transform::SCALE).map_or(1., |m| m.value))
+    }
+
+    /// Sets the horizontal offset from the content's position in the layout.
+    // does nothing if lazy
+    pub fn x(mut self, x: f32) -> Self {
+        self.x = Some(x);
+        self
+    }
+
+    /// Sets the vertical offset from the content's position in the layout.
+    // does nothing if lazy
+    pub fn y(mut self, y: f32) -> Self {
+        self.y = Some(y);
+        self
+    }
+
+    /// Sets both offsets from the content's position in the layout.
+    // does nothing if lazy
+    pub fn translate(self, translation: impl Into<Vector>) -> Self {
+        let translation = translation.into();
+        self.x(translation.x).y(translation.y)
+    }
+
+    /// Sets the scale of the content, around its center.
+    // does nothing if lazy
+    pub fn scale(mut self, scale: f32) -> Self {
+        self.scale = Some(scale);
+        self
+    }
+
+    timing_overrides! {
+        x_timing: x_at, x_ease;
+        y_timing: y_at, y_ease;
+        scale_timing: scale_at, scale_ease;
+    }
+
+    // does nothing if eager
+    pub fn blend_velocity(mut self) -> Self {
+        self.blend_velocity = true;
+        self
+    }
+}
+
+properties! {
+    Transform(transform) {
+        /// The horizontal offset.
+        X =>
+            transform.x.map(|x| transform.x_timing.eager(transform.at, x, transform.ease)),
+            Some(transform.x_timing.lazy(transform.at, 0., transform.ease).with_velocity(transform.blend_velocity));
+        /// The vertical offset.
+        Y =>
+            transform.y.map(|y| transform.y_timing.eager(transform.at, y, transform.ease)),
+            Some(transform.y_timing.lazy(transform.at, 0., transform.ease).with_velocity(transform.blend_velocity));
+        /// The scale.
+        SCALE =>
+            transform.scale.map(|s| transform.scale_timing.eager(transform.at, s, transform.ease)),
+            Some(transform.scale_timing.lazy(transform.at, 1., transform.ease).with_velocity(transform.blend_velocity));
+    }
+}
@@ -135,21 +135,38 @@
     clippy::type_complexity
 )]
 #![cfg_attr(docsrs, feature(doc_cfg))]
+/// Play animations on many widgets one after another, or together, as one unit.
+pub mod group;
+/// Animate items in and out as they are added and removed.
+pub mod presence;
 pub mod reexports;
 /// The main timeline for your animations!
 pub mod timeline;
+/// Animate any [`Animatable`] value as a whole.
+pub mod track;
 /// Additional Widgets that Cosmic Time uses for more advanced animations.
 pub mod widget;
 
+mod animatable;
+mod color;
 mod keyframes;
 mod utils;
 
+pub use crate::animatable::Animatable;
+pub use crate::color::ColorSpace;
+pub use crate::group::{Group, Member};
 #[cfg(feature = "libcosmic")]
 pub use crate::keyframes::cards;
+pub use crate::keyframes::Repeat;
 pub use crate::keyframes::{
     button, chain, column, container, id, lazy, row, space, style_button, style_container, toggler,
+    transform,
 };
-pub use crate::timeline::{Chain, Timeline};
+pub use crate::presence::Presence;
+pub use crate::timeline::{
+    AnimationEvent, Chain, IntoChains, Property, PropertyKey, Stagger, Timeline,
+};
+pub use crate::track::Track;
 
 #[cfg(feature = "libcosmic")]
 pub use cosmic::iced::time::{Duration, Instant};
@@ -179,6 +196,15 @@ pub trait Tween: std::fmt::Debug + Copy {
     /// Takes a linear percentage, and returns tweened value.
     /// p = percent complete as decimal
     fn tween(&self, p: f32) -> f32;
+
+    /// The slope of the tween at a linear percentage.
+    /// Used to calculate the velocity of an animation.
+    /// p = percent complete as decimal
+    fn derivative(&self, p: f32) -> f32 {
+        const H: f32 = 1e-3;
+        let (low, high) = ((p - H).max(0.), (p + H).min(1.));
+        (self.tween(high) - self.tween(low)) / (high - low)
+    }
 }
 
 /// Speed Controlled Animation use this type.
@@ -187,7 +213,7 @@ pub trait Tween: std::fmt::Debug + Copy {
 /// type auto-calculates the time for you.
 /// Very useful with lazy keyframes.
 /// Designed to have an API very similar to `std::time::Duration`
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub enum Speed {
     /// Whole number of seconds to move per second.
     PerSecond(f32),
@@ -242,14 +268,152 @@ impl Speed {
     }
 }
 
+/// Physics based animations use this type.
+/// Rather than specifying the time (`Duration`) or the
+/// `Speed` between links in the animation chain, the link
+/// is driven by a damped spring. The time it takes the spring
+/// to settle is auto-calculated for you.
+/// If a lazy keyframe interrupts a running spring, the spring's
+/// current velocity is carried over into the next link.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Spring {
+    /// How strongly the spring pulls towards its target.
+    pub stiffness: f32,
+    /// How strongly the spring resists motion. Lower values oscillate more.
+    pub damping: f32,
+    /// The mass attached to the spring. Higher values are more sluggish.
+    pub mass: f32,
+}
+
+impl std::default::Default for Spring {
+    fn default() -> Self {
+        Spring::new(170., 26., 1.)
+    }
+}
+
+impl Spring {
+    // A spring is considered settled once it is within this fraction
+    // of its starting distance (or velocity) from the target.
+    const REST_DELTA: f32 = 0.001;
+    // Springs that would never settle (no damping) are cut off here.
+    const MAX_SETTLE: f32 = 60.;
+
+    /// Creates a new `Spring` from the stiffness, damping and mass.
+    #[must_use]
+    pub fn new(stiffness: f32, damping: f32, mass: f32) -> Self {
+        Spring {
+            stiffness,
+            damping,
+            mass,
+        }
+    }
+
+    /// A slow spring with no overshoot.
+    #[must_use]
+    pub fn gentle() -> Self {
+        Spring::new(120., 14., 1.)
+    }
+
+    /// A bouncy spring that overshoots its target a few times.
+    #[must_use]
+    pub fn wobbly() -> Self {
+        Spring::new(180., 12., 1.)
+    }
+
+    /// A fast spring with little overshoot.
+    #[must_use]
+    pub fn stiff() -> Self {
+        Spring::new(210., 20., 1.)
+    }
+
+    // Undamped angular frequency, and damping ratio.
+    fn omega_zeta(self) -> (f32, f32) {
+        let omega = (self.stiffness / self.mass).sqrt();
+        let zeta = self.damping / (2. * (self.stiffness * self.mass).sqrt());
+        (omega, zeta)
+    }
+
+    /// The position (relative to the target) and velocity of the spring
+    /// `t` seconds after being released at `x0` from the target with
+    /// velocity `v0`.
+    #[must_use]
+    pub fn solve(self, x0: f32, v0: f32, t: f32) -> (f32, f32) {
+        let (omega, zeta) = self.omega_zeta();
+        if zeta < 1. {
+            // Under damped
+            let a = zeta * omega;
+            let omega_d = omega * (1. - zeta.powi(2)).sqrt();
+            let b = (v0 + a * x0) / omega_d;
+            let (sin, cos) = (omega_d * t).sin_cos();
+            let envelope = (-a * t).exp();
+            (
+                envelope * (x0 * cos + b * sin),
+                envelope * (v0 * cos - (a * b + x0 * omega_d) * sin),
+            )
+        } else if float_cmp::approx_eq!(f32, zeta, 1., epsilon = 1e-4) {
+            // Critically damped
+            let c = v0 + omega * x0;
+            let envelope = (-omega * t).exp();
+            ((x0 + c * t) * envelope, (v0 - omega * c * t) * envelope)
+        } else {
+            // Over damped
+            let root = (zeta.powi(2) - 1.).sqrt();
+            let r1 = -omega * (zeta - root);
+            let r2 = -omega * (zeta + root);
+            let c2 = (v0 - r1 * x0) / (r2 - r1);
+            let c1 = x0 - c2;
+            let (e1, e2) = ((r1 * t).exp(), (r2 * t).exp());
+            (c1 * e1 + c2 * e2, r1 * c1 * e1 + r2 * c2 * e2)
+        }
+    }
+
+    fn calc_duration(self, first: f32, second: f32, velocity: f32) -> Duration {
+        let (omega, zeta) = self.omega_zeta();
+        let x0 = first - second;
+        let epsilon = Spring::REST_DELTA * x0.abs().max(velocity.abs() / omega);
+        if epsilon <= 0. || !epsilon.is_finite() {
+            return Duration::ZERO;
+        }
+
+        // Solve for when the envelope of the oscillation drops below `epsilon`.
+        let secs = if zeta < 1. {
+            let a = zeta * omega;
+            let b = (velocity + a * x0) / (omega * (1. - zeta.powi(2)).sqrt());
+            (x0.hypot(b) / epsilon).ln() / a
+        } else if float_cmp::approx_eq!(f32, zeta, 1., epsilon = 1e-4) {
+            let c = (velocity + omega * x0).abs();
+            // Fixed point iteration of t = ln((|x0| + |c|t) / epsilon) / omega
+            (0..8).fold(0., |t: f32, _| {
+                ((x0.abs() + c * t) / epsilon).ln().max(0.) / omega
+            })
+        } else {
+            let root = (zeta.powi(2) - 1.).sqrt();
+            let r1 = -omega * (zeta - root);
+            let r2 = -omega * (zeta + root);
+            let c2 = (velocity - r1 * x0) / (r2 - r1);
+            let c1 = x0 - c2;
+            ((c1.abs() + c2.abs()) / epsilon).ln() / -r1
+        };
+
+        if secs.is_nan() || secs <= 0. {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f32(secs.min(Spring::MAX_SETTLE))
+        }
+    }
+}
+
 /// A container type so that the API user can specify Either
-/// Time controlled animations, or speed controlled animations.
-#[derive(Debug, Copy, Clone)]
+/// Time controlled animations, speed controlled animations,
+/// or spring (physics) controlled animations.
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub enum MovementType {
     /// Keyframe is time controlled.
     Duration(Duration),
     /// keyframe is speed controlled.
     Speed(Speed),
+    /// Keyframe is spring controlled.
+    Spring(Spring),
 }
 
 impl From<Duration> for MovementType {
@@ -264,6 +428,12 @@ impl From<Speed> for MovementType {
     }
 }
 
+impl From<Spring> for MovementType {
+    fn from(spring: Spring) -> Self {
+        MovementType::Spring(spring)
+    }
+}
+
 macro_rules! tween {
     ($($x:ident),*) => {
         #[derive(Debug, Copy, Clone)]
@@ -284,9 +454,54 @@ macro_rules! tween {
                 }
             }
         }
+
+        impl Ease {
+            /// The mirror image of this ease, for playing an animation backwards.
+            /// `In` eases become `Out` eases, and vice versa.
+            #[must_use]
+            pub fn reverse(self) -> Self {
+                match self {
+                    $(
+                        Ease::$x(ease) => ease.reverse().into(),
+                    )*
+                }
+            }
+        }
     };
 }
 
+// The `In`, and `Out` variants of an ease family mirror each other.
+macro_rules! reverse_in_out {
+    ($($x:ident),*) => {
+        $(
+            impl $x {
+                /// The mirror image of this ease, for playing an animation backwards.
+                #[must_use]
+                pub fn reverse(self) -> Self {
+                    match self {
+                        $x::In => $x::Out,
+                        $x::Out => $x::In,
+                        $x::InOut => $x::InOut,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+reverse_in_out!(
+    Quadratic,
+    Cubic,
+    Quartic,
+    Quintic,
+    Sinusoidal,
+    Exponential,
+    Circular,
+    Elastic,
+    Back,
+    Bounce
+);
+
 tween!(
     Linear,
     Quadratic,
@@ -298,7 +513,10 @@ tween!(
     Circular,
     Elastic,
     Back,
-    Bounce
+    Bounce,
+    CubicBezier,
+    Steps,
+    Custom
 );
 
 /// Used to set a linear animation easing.
@@ -315,6 +533,14 @@ impl Tween for Linear {
     }
 }
 
+impl Linear {
+    /// The mirror image of this ease, for playing an animation backwards.
+    #[must_use]
+    pub fn reverse(self) -> Self {
+        self
+    }
+}
+
 impl From<Linear> for Ease {
     fn from(linear: Linear) -> Self {
         Ease::Linear(linear)
@@ -332,8 +558,6 @@ pub enum Quadratic {
     /// y = (1/2)((2x)^2)             ; [0, 0.5)
     /// y = -(1/2)((2x-1)*(2x-3) - 1) ; [0.5, 1]
     InOut,
-    /// A Bezier Curve TODO
-    Bezier(i32),
 }
 
 impl Tween for Quadratic {
@@ -348,7 +572,6 @@ impl Tween for Quadratic {
                     (-2. * p.powi(2)) + p.mul_add(4., -1.)
                 }
             }
-            Quadratic::Bezier(_n) => p,
         }
     }
 }
@@ -730,6 +953,256 @@ impl From<Bounce> for Ease {
     }
 }
 
+/// Used to set a cubic bezier animation easing.
+/// The same curves as CSS's `cubic-bezier(x1, y1, x2, y2)`.
+/// The curve starts at (0, 0) and ends at (1, 1), with
+/// (x1, y1) and (x2, y2) as its control points.
+#[derive(Debug, Copy, Clone)]
+pub struct CubicBezier {
+    x1: f32,
+    y1: f32,
+    x2: f32,
+    y2: f32,
+}
+
+impl CubicBezier {
+    /// CSS's `ease`.
+    pub const EASE: CubicBezier = CubicBezier::new(0.25, 0.1, 0.25, 1.0);
+    /// CSS's `ease-in`.
+    pub const EASE_IN: CubicBezier = CubicBezier::new(0.42, 0.0, 1.0, 1.0);
+    /// CSS's `ease-out`.
+    pub const EASE_OUT: CubicBezier = CubicBezier::new(0.0, 0.0, 0.58, 1.0);
+    /// CSS's `ease-in-out`.
+    pub const EASE_IN_OUT: CubicBezier = CubicBezier::new(0.42, 0.0, 0.58, 1.0);
+    /// Material Design's standard easing.
+    pub const STANDARD: CubicBezier = CubicBezier::new(0.2, 0.0, 0.0, 1.0);
+    /// Material Design's emphasized decelerate easing.
+    pub const DECELERATE: CubicBezier = CubicBezier::new(0.05, 0.7, 0.1, 1.0);
+    /// Material Design's emphasized accelerate easing.
+    pub const ACCELERATE: CubicBezier = CubicBezier::new(0.3, 0.0, 0.8, 0.15);
+
+    /// Creates a new cubic bezier curve from two control points.
+    /// x1 and x2 are clamped to [0, 1] so that the curve is a function of time.
+    #[must_use]
+    pub const fn new(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        CubicBezier {
+            x1: x1.clamp(0., 1.),
+            y1,
+            x2: x2.clamp(0., 1.),
+            y2,
+        }
+    }
+
+    // B(t) for one axis, with the end points fixed at 0 and 1.
+    fn sample(a1: f32, a2: f32, t: f32) -> f32 {
+        let c = 3. * a1;
+        let b = 3. * (a2 - a1) - c;
+        let a = 1. - c - b;
+        ((a * t + b) * t + c) * t
+    }
+
+    // B'(t) for one axis.
+    fn sample_derivative(a1: f32, a2: f32, t: f32) -> f32 {
+        let c = 3. * a1;
+        let b = 3. * (a2 - a1) - c;
+        let a = 1. - c - b;
+        (3. * a * t + 2. * b) * t + c
+    }
+
+    // Find the curve parameter t where x(t) = x.
+    fn solve_t(&self, x: f32) -> f32 {
+        const EPSILON: f32 = 1e-6;
+
+        // Newton's method converges quickly for most curves.
+        let mut t = x;
+        for _ in 0..8 {
+            let error = CubicBezier::sample(self.x1, self.x2, t) - x;
+            if error.abs() < EPSILON {
+                return t;
+            }
+            let slope = CubicBezier::sample_derivative(self.x1, self.x2, t);
+            if slope.abs() < EPSILON {
+                break;
+            }
+            t -= error / slope;
+        }
+
+        // Fall back to bisection, which always converges.
+        let (mut low, mut high) = (0., 1.);
+        t = x;
+        while high - low > EPSILON {
+            let current = CubicBezier::sample(self.x1, self.x2, t);
+            if (current - x).abs() < EPSILON {
+                break;
+            }
+            if current < x {
+                low = t;
+            } else {
+                high = t;
+            }
+            t = (low + high) / 2.;
+        }
+        t
+    }
+}
+
+impl Tween for CubicBezier {
+    fn tween(&self, p: f32) -> f32 {
+        if p <= 0. {
+            0.
+        } else if p >= 1. {
+            1.
+        } else {
+            CubicBezier::sample(self.y1, self.y2, self.solve_t(p))
+        }
+    }
+}
+
+impl CubicBezier {
+    /// The mirror image of this ease, for playing an animation backwards.
+    #[must_use]
+    pub fn reverse(self) -> Self {
+        CubicBezier::new(1. - self.x2, 1. - self.y2, 1. - self.x1, 1. - self.y1)
+    }
+}
+
+impl From<CubicBezier> for Ease {
+    fn from(bezier: CubicBezier) -> Self {
+        Ease::CubicBezier(bezier)
+    }
+}
+
+/// Where the jumps of a [`Steps`] easing happen.
+/// The same as CSS's `steps()` jump terms.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum Jump {
+    /// The first jump happens as soon as the animation starts.
+    Start,
+    /// The last jump happens when the animation ends.
+    #[default]
+    End,
+    /// No jump at the start or end, the value is held at both ends.
+    None,
+    /// Jumps both when the animation starts and when it ends.
+    Both,
+}
+
+/// Used to set a stepped animation easing.
+/// Rather than smoothly moving between values, the value
+/// jumps between `n` evenly spaced steps. Great for sprite-style
+/// and blinking animations.
+#[derive(Debug, Copy, Clone)]
+pub struct Steps {
+    steps: u16,
+    jump: Jump,
+}
+
+impl Steps {
+    /// Creates a new stepped easing with `steps` intervals.
+    /// `steps` must be at least 1, or 2 for [`Jump::None`].
+    #[must_use]
+    pub fn new(steps: u16, jump: Jump) -> Self {
+        let min = if jump == Jump::None { 2 } else { 1 };
+        Steps {
+            steps: steps.max(min),
+            jump,
+        }
+    }
+}
+
+impl Tween for Steps {
+    fn tween(&self, p: f32) -> f32 {
+        let steps = f32::from(self.steps);
+        let mut step = (p * steps).floor();
+        if matches!(self.jump, Jump::Start | Jump::Both) {
+            step += 1.;
+        }
+        let jumps = match self.jump {
+            Jump::Start | Jump::End => steps,
+            Jump::None => steps - 1.,
+            Jump::Both => steps + 1.,
+        };
+        step.clamp(0., jumps) / jumps
+    }
+}
+
+impl Steps {
+    /// The mirror image of this ease, for playing an animation backwards.
+    #[must_use]
+    pub fn reverse(self) -> Self {
+        let jump = match self.jump {
+            Jump::Start => Jump::End,
+            Jump::End => Jump::Start,
+            jump => jump,
+        };
+        Steps::new(self.steps, jump)
+    }
+}
+
+impl From<Steps> for Ease {
+    fn from(steps: Steps) -> Self {
+        Ease::Steps(steps)
+    }
+}
+
+/// Used to set your own animation easing.
+/// Takes a linear percentage, and returns the tweened value.
+/// ```ignore
+/// fn smoothstep(p: f32) -> f32 {
+///     p * p * (3. - 2. * p)
+/// }
+///
+/// container(Duration::from_millis(500)).width(100.).ease(Custom::new(smoothstep))
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct Custom {
+    tween: fn(f32) -> f32,
+    // Played as `1 - f(1 - p)`.
+    mirrored: bool,
+}
+
+impl Tween for Custom {
+    fn tween(&self, p: f32) -> f32 {
+        if self.mirrored {
+            1. - (self.tween)(1. - p)
+        } else {
+            (self.tween)(p)
+        }
+    }
+}
+
+impl Custom {
+    /// Creates a custom ease from a function.
+    #[must_use]
+    pub fn new(tween: fn(f32) -> f32) -> Self {
+        Custom {
+            tween,
+            mirrored: false,
+        }
+    }
+
+    /// The mirror image of this ease, for playing an animation backwards.
+    #[must_use]
+    pub fn reverse(self) -> Self {
+        Custom {
+            mirrored: !self.mirrored,
+            ..self
+        }
+    }
+}
+
+impl From<Custom> for Ease {
+    fn from(custom: Custom) -> Self {
+        Ease::Custom(custom)
+    }
+}
+
+impl From<fn(f32) -> f32> for Ease {
+    fn from(f: fn(f32) -> f32) -> Self {
+        Ease::Custom(Custom::new(f))
+    }
+}
+
 #[cfg(test)]
 mod test {
     #![allow(clippy::excessive_precision)]
@@ -804,7 +1277,41 @@ mod test {
         assert_eq!(1.00, r(Quadratic::InOut.tween(1.0)));
     }
 
-    // TODO Bezier
+    #[test]
+    // A bezier with control points on the line y = x is linear.
+    fn cubic_bezier_linear() {
+        let bezier = CubicBezier::new(0.25, 0.25, 0.75, 0.75);
+        assert_eq!(0.0, r(bezier.tween(0.0)));
+        assert_eq!(0.1, r(bezier.tween(0.1)));
+        assert_eq!(0.2, r(bezier.tween(0.2)));
+        assert_eq!(0.3, r(bezier.tween(0.3)));
+        assert_eq!(0.4, r(bezier.tween(0.4)));
+        assert_eq!(0.5, r(bezier.tween(0.5)));
+        assert_eq!(0.6, r(bezier.tween(0.6)));
+        assert_eq!(0.7, r(bezier.tween(0.7)));
+        assert_eq!(0.8, r(bezier.tween(0.8)));
+        assert_eq!(0.9, r(bezier.tween(0.9)));
+        assert_eq!(1.0, r(bezier.tween(1.0)));
+    }
+
+    #[test]
+    // CSS `ease-in-out`
+    fn cubic_bezier_ease_in_out() {
+        let bezier = CubicBezier::EASE_IN_OUT;
+        assert_eq!(0.000, (bezier.tween(0.0) * 1000.).round() / 1000.);
+        assert_eq!(0.020, (bezier.tween(0.1) * 1000.).round() / 1000.);
+        assert_eq!(0.500, (bezier.tween(0.5) * 1000.).round() / 1000.);
+        assert_eq!(0.980, (bezier.tween(0.9) * 1000.).round() / 1000.);
+        assert_eq!(1.000, (bezier.tween(1.0) * 1000.).round() / 1000.);
+    }
+
+    #[test]
+    // y may leave [0, 1] for "back" like curves.
+    fn cubic_bezier_overshoot() {
+        let bezier = CubicBezier::new(0.34, 1.56, 0.64, 1.);
+        assert!(bezier.tween(0.5) > 1.);
+        assert_eq!(1.0, bezier.tween(1.0));
+    }
 
     #[test]
     // Modeled after the cubic y = x^3
@@ -1262,4 +1769,666 @@ mod test {
         assert_eq!(0.956_121, r(Bounce::InOut.tween(0.9)));
         assert_eq!(1.000_000, r(Bounce::InOut.tween(1.0)));
     }
+
+    #[test]
+    fn spring_starts_at_rest_position() {
+        for spring in [
+            Spring::default(),
+            Spring::new(100., 20., 1.),
+            Spring::new(100., 60., 1.),
+        ] {
+            let (x, v) = spring.solve(-100., 25., 0.);
+            assert_eq!(-100., r(x));
+            assert_eq!(25., r(v));
+        }
+    }
+
+    #[test]
+    fn spring_settles() {
+        // under, critically, and over damped
+        for spring in [
+            Spring::default(),
+            Spring::new(100., 20., 1.),
+            Spring::new(100., 60., 1.),
+        ] {
+            let t = spring.calc_duration(0., 100., 0.).as_secs_f32();
+            assert!(t > 0.);
+            let (x, _v) = spring.solve(-100., 0., t);
+            assert!(x.abs() <= 0.1 + f32::EPSILON);
+        }
+    }
+
+    #[test]
+    fn spring_carries_velocity() {
+        let spring = Spring::default();
+        let at_rest = spring.calc_duration(0., 0., 0.);
+        let moving = spring.calc_duration(0., 0., 50.);
+        assert_eq!(Duration::ZERO, at_rest);
+        assert!(moving > Duration::ZERO);
+        // Moving away from the target delays settling.
+        assert!(spring.calc_duration(0., 100., -50.) > spring.calc_duration(0., 100., 0.));
+    }
+
+    #[test]
+    fn spring_wobbly_overshoots() {
+        let spring = Spring::wobbly();
+        let overshoots = (1..100).any(|i| spring.solve(-1., 0., i as f32 / 100.).0 > 0.);
+        assert!(overshoots);
+    }
+
+    #[test]
+    fn steps_end() {
+        let steps = Steps::new(4, Jump::End);
+        assert_eq!(0.00, steps.tween(0.0));
+        assert_eq!(0.00, steps.tween(0.2));
+        assert_eq!(0.25, steps.tween(0.3));
+        assert_eq!(0.50, steps.tween(0.5));
+        assert_eq!(0.75, steps.tween(0.9));
+        assert_eq!(1.00, steps.tween(1.0));
+    }
+
+    #[test]
+    fn steps_start() {
+        let steps = Steps::new(4, Jump::Start);
+        assert_eq!(0.25, steps.tween(0.0));
+        assert_eq!(0.25, steps.tween(0.2));
+        assert_eq!(0.50, steps.tween(0.3));
+        assert_eq!(1.00, steps.tween(0.9));
+        assert_eq!(1.00, steps.tween(1.0));
+    }
+
+    #[test]
+    fn steps_none() {
+        let steps = Steps::new(3, Jump::None);
+        assert_eq!(0.0, steps.tween(0.0));
+        assert_eq!(0.5, steps.tween(0.5));
+        assert_eq!(1.0, steps.tween(0.7));
+        assert_eq!(1.0, steps.tween(1.0));
+    }
+
+    #[test]
+    fn steps_both() {
+        let steps = Steps::new(3, Jump::Both);
+        assert_eq!(0.25, steps.tween(0.0));
+        assert_eq!(0.50, steps.tween(0.5));
+        assert_eq!(1.00, steps.tween(1.0));
+    }
+
+    #[test]
+    fn custom() {
+        fn smoothstep(p: f32) -> f32 {
+            p * p * (3. - 2. * p)
+        }
+        let ease: Ease = Custom::new(smoothstep).into();
+        assert_eq!(0.0, ease.tween(0.0));
+        assert_eq!(0.5, ease.tween(0.5));
+        assert_eq!(1.0, ease.tween(1.0));
+    }
+
+    #[test]
+    fn derivative() {
+        let r = |val: f32| (val * 100.).round() / 100.;
+        assert_eq!(1.00, r(Linear::InOut.derivative(0.5)));
+        assert_eq!(0.00, r(Quadratic::In.derivative(0.0)));
+        assert_eq!(1.00, r(Quadratic::In.derivative(0.5)));
+        assert_eq!(2.00, r(Quadratic::In.derivative(1.0)));
+    }
+
+    #[test]
+    fn reverse() {
+        fn cube(p: f32) -> f32 {
+            p * p * p
+        }
+        for ease in [
+            Ease::from(Cubic::In),
+            Back::Out.into(),
+            Bounce::In.into(),
+            CubicBezier::EASE.into(),
+            Custom::new(cube).into(),
+            Custom::new(cube).reverse().into(),
+        ] {
+            let reversed = ease.reverse();
+            for p in [0.0, 0.1, 0.25, 0.5, 0.75, 0.9, 1.0] {
+                assert_eq!(r(1. - ease.tween(1. - p)), r(reversed.tween(p)));
+            }
+        }
+    }
+
+    #[test]
+    fn lengths() {
+        use crate::keyframes::{get_length, Container};
+        use crate::reexports::iced_core::Length;
+        use crate::widget::LengthType;
+
+        let id = id::Container::unique();
+        let start = Instant::now();
+        let at = |millis| start + Duration::from_millis(millis);
+        let mut timeline = Timeline::new();
+        let animation = chain![
+            id.clone(),
+            container(Duration::ZERO)
+                .width(0.)
+                .height(Length::FillPortion(3)),
+            container(Duration::from_secs(1))
+                .width(Length::Fill)
+                .height(Length::Shrink),
+        ];
+        let _ = timeline.set_chain(animation);
+        timeline.start_at(start);
+        timeline.now(at(500));
+
+        let iced_id = id.clone().into();
+        let width = |timeline: &Timeline| {
+            get_length(
+                &iced_id,
+                timeline,
+                Container::WIDTH,
+                Container::WIDTH_KIND,
+                Length::Shrink,
+            )
+        };
+        let height = |timeline: &Timeline| {
+            get_length(
+                &iced_id,
+                timeline,
+                Container::HEIGHT,
+                Container::HEIGHT_KIND,
+                Length::Shrink,
+            )
+        };
+        assert_eq!(
+            LengthType::Blend(Length::Fixed(0.), Length::Fill, 0.5),
+            width(&timeline)
+        );
+        assert_eq!(
+            LengthType::Blend(Length::FillPortion(3), Length::Shrink, 0.5),
+            height(&timeline)
+        );
+        assert_eq!(50., width(&timeline).resolve(100., 20.));
+        assert_eq!(60., height(&timeline).resolve(100., 20.));
+
+        timeline.now(at(1000));
+        assert_eq!(LengthType::Static(Length::Fill), width(&timeline));
+
+        // Lazy keyframes continue from a length, never from a blend of two.
+        let _ = timeline.set_chain(chain![
+            id.clone(),
+            container(Duration::ZERO).width(200.).height(200.),
+            container(Duration::from_secs(1))
+                .width(100.)
+                .height(Length::Fill),
+        ]);
+        timeline.start_at(at(1000));
+        timeline.now(at(1750));
+        let _ = timeline.set_chain(chain![
+            id.clone(),
+            lazy::container(Duration::ZERO),
+            container(Duration::from_secs(1))
+                .width(Length::Shrink)
+                .height(100.),
+        ]);
+        timeline.start_at(at(1750));
+        assert_eq!(
+            LengthType::Blend(Length::Fixed(125.), Length::Shrink, 0.),
+            width(&timeline)
+        );
+        assert_eq!(
+            LengthType::Blend(Length::Fill, Length::Fixed(100.), 0.),
+            height(&timeline)
+        );
+        timeline.now(at(2250));
+        assert_eq!(
+            LengthType::Blend(Length::Fixed(125.), Length::Shrink, 0.5),
+            width(&timeline)
+        );
+
+        // Only `Fixed` lengths have a distance to travel at a speed.
+        let _ = timeline.set_chain(chain![
+            id.clone(),
+            container(Duration::ZERO).width(200.),
+            container(Speed::per_secs(100.)).width(Length::Shrink),
+            container(Speed::per_secs(100.)).width(100.),
+        ]);
+        timeline.start_at(at(3000));
+        assert_eq!(Some(Duration::from_secs(1)), timeline.remaining(&iced_id));
+        timeline.now(at(3500));
+        assert_eq!(LengthType::Static(Length::Fixed(150.)), width(&timeline));
+        let _ = timeline.set_chain(chain![
+            id,
+            container(Duration::ZERO).width(200.),
+            container(Speed::per_secs(100.)).width(Length::Fill),
+        ]);
+        timeline.start_at(at(4000));
+        assert_eq!(Some(Duration::ZERO), timeline.remaining(&iced_id));
+        assert_eq!(LengthType::Static(Length::Fill), width(&timeline));
+    }
+
+    #[test]
+    fn translate_and_scale() {
+        use crate::keyframes::Transform;
+
+        let id = id::Transform::unique();
+        let start = Instant::now();
+        let mut timeline = Timeline::new();
+        let animation = chain![
+            id,
+            transform(Duration::ZERO).translate([-100., 0.]).scale(0.),
+            transform(Duration::from_secs(1))
+                .translate([0., 50.])
+                .scale(1.)
+                .scale_at(Duration::from_millis(500)),
+        ];
+        let _ = timeline.set_chain(animation);
+        timeline.start_at(start);
+
+        let id = id.into();
+        timeline.now(start + Duration::from_millis(250));
+        assert_eq!(-75., timeline.get(&id, Transform::X).unwrap().value);
+        assert_eq!(12.5, timeline.get(&id, Transform::Y).unwrap().value);
+        assert_eq!(0.5, timeline.get(&id, Transform::SCALE).unwrap().value);
+        timeline.now(start + Duration::from_millis(500));
+        assert_eq!(1., timeline.get(&id, Transform::SCALE).unwrap().value);
+    }
+
+    #[test]
+    fn appearance() {
+        use crate::keyframes::{get_appearance, StyleContainer};
+        use crate::reexports::iced_core::{Background, Color};
+
+        let id = id::StyleContainer::unique();
+        let start = Instant::now();
+        let mut timeline = Timeline::new();
+        let animation = chain![
+            id.clone(),
+            style_container(Duration::ZERO)
+                .background(Color::BLACK)
+                .border_width(0.),
+            style_container(Duration::from_secs(1))
+                .background(Color::WHITE)
+                .border_width(2.)
+                .border_radius(8.),
+        ];
+        let _ = timeline.set_chain(animation);
+        timeline.start_at(start);
+
+        let iced_id = id.clone().into();
+        timeline.now(start + Duration::from_millis(500));
+        let appearance = get_appearance(
+            &iced_id,
+            &timeline,
+            StyleContainer::BACKGROUND_R,
+            StyleContainer::BACKGROUND_SET,
+            StyleContainer::BACKGROUND_ANGLE,
+            ColorSpace::Srgb,
+        );
+        assert_eq!(
+            Some(Background::Color(Color::from_rgb(0.5, 0.5, 0.5))),
+            appearance.background
+        );
+        assert_eq!(Some(1.), appearance.border_width);
+        // Never set before, so there is nothing to blend from.
+        assert_eq!(Some(8.), appearance.border_radius);
+        assert_eq!(None, appearance.text_color);
+        assert_eq!(None, appearance.border_color);
+
+        // Lazy keyframes continue from the current appearance.
+        let animation = chain![
+            id,
+            lazy::style_container(Duration::ZERO),
+            style_container(Duration::from_secs(1)).background(Color::BLACK),
+        ];
+        let _ = timeline.set_chain(animation);
+        timeline.now(start + Duration::from_secs(1));
+        timeline.start_at(start + Duration::from_secs(1));
+        timeline.now(start + Duration::from_millis(1500));
+        let appearance = get_appearance(
+            &iced_id,
+            &timeline,
+            StyleContainer::BACKGROUND_R,
+            StyleContainer::BACKGROUND_SET,
+            StyleContainer::BACKGROUND_ANGLE,
+            ColorSpace::Srgb,
+        );
+        assert_eq!(
+            Some(Background::Color(Color::from_rgb(0.5, 0.5, 0.5))),
+            appearance.background
+        );
+        assert_eq!(Some(8.), appearance.border_radius);
+        assert_eq!(None, appearance.text_color);
+    }
+
+    #[test]
+    fn gradient_background() {
+        use crate::keyframes::{get_appearance, StyleContainer};
+        use crate::reexports::iced_core::{gradient::Linear, Background, Color, Radians};
+        use crate::widget::blend_backgrounds;
+
+        let one = Linear::new(Radians(1.))
+            .add_stop(0., Color::WHITE)
+            .add_stop(1., Color::from_rgb(1., 0., 0.));
+        let two = Linear::new(Radians(0.))
+            .add_stop(0., Color::from_rgb(0., 0., 1.))
+            .add_stop(0.5, Color::WHITE)
+            .add_stop(1., Color::from_rgb(0., 0., 1.));
+        let id = id::StyleContainer::unique();
+        let start = Instant::now();
+        let mut timeline = Timeline::new();
+        let _ = timeline.set_chain(chain![
+            id.clone(),
+            style_container(Duration::ZERO).background(Color::BLACK),
+            style_container(Duration::from_secs(1)).background(one),
+            style_container(Duration::from_secs(1)).background(two),
+        ]);
+        timeline.start_at(start);
+
+        let iced_id = id.into();
+        let background = |timeline: &Timeline| {
+            get_appearance(
+                &iced_id,
+                timeline,
+                StyleContainer::BACKGROUND_R,
+                StyleContainer::BACKGROUND_SET,
+                StyleContainer::BACKGROUND_ANGLE,
+                ColorSpace::Srgb,
+            )
+            .background
+        };
+        let blend = |one: Background, two: Background| {
+            blend_backgrounds(Some(one), Some(two), 0.5, ColorSpace::Srgb)
+        };
+        // A solid color blends into a gradient, and gradients with a
+        // different number of stops into each other.
+        timeline.now(start + Duration::from_millis(500));
+        assert_eq!(
+            blend(Color::BLACK.into(), one.into()),
+            background(&timeline)
+        );
+        timeline.now(start + Duration::from_millis(1500));
+        assert_eq!(blend(one.into(), two.into()), background(&timeline));
+        timeline.now(start + Duration::from_secs(2));
+        assert_eq!(Some(two.into()), background(&timeline));
+    }
+
+    #[test]
+    fn appearance_overshoot() {
+        use crate::keyframes::{get_appearance, StyleContainer};
+        use crate::reexports::iced_core::{Background, Color};
+
+        let id = id::StyleContainer::unique();
+        let iced_id = id.clone().into();
+        let start = Instant::now();
+        let mut timeline = Timeline::new();
+        let _ = timeline.set_chain(chain![
+            id.clone(),
+            style_container(Duration::ZERO)
+                .background(Color::BLACK)
+                .border_width(0.),
+            style_container(Duration::from_secs(1))
+                .background(Color::WHITE)
+                .border_width(2.)
+                .ease(Back::In),
+        ]);
+        timeline.start_at(start);
+
+        // `Back::In` dips below zero before it heads to the end.
+        timeline.now(start + Duration::from_millis(300));
+        let appearance = get_appearance(
+            &iced_id,
+            &timeline,
+            StyleContainer::BACKGROUND_R,
+            StyleContainer::BACKGROUND_SET,
+            StyleContainer::BACKGROUND_ANGLE,
+            ColorSpace::Srgb,
+        );
+        assert!(appearance.border_width.is_some());
+        assert!(appearance.background.is_some());
+
+        // A lazy keyframe picks up the negative values. They are still set,
+        // so the new link blends from them instead of snapping to its end.
+        let _ = timeline.set_chain(chain![
+            id,
+            lazy::style_container(Duration::ZERO),
+            style_container(Duration::from_secs(1))
+                .background(Color::WHITE)
+                .border_width(4.),
+        ]);
+        timeline.start_at(start + Duration::from_millis(300));
+        timeline.now(start + Duration::from_millis(800));
+        let appearance = get_appearance(
+            &iced_id,
+            &timeline,
+            StyleContainer::BACKGROUND_R,
+            StyleContainer::BACKGROUND_SET,
+            StyleContainer::BACKGROUND_ANGLE,
+            ColorSpace::Srgb,
+        );
+        let width = appearance.border_width.unwrap();
+        assert!((1.9..2.).contains(&width), "{width}");
+        let Some(Background::Color(background)) = appearance.background else {
+            panic!("{:?}", appearance.background);
+        };
+        assert!((background.r - 0.5).abs() < 0.01, "{background:?}");
+    }
+
+    #[test]
+    fn property_keys() {
+        use crate::keyframes::{Container, Repeat, StyleContainer, Transform};
+        use crate::reexports::iced_core::widget;
+        use crate::timeline::Frame;
+
+        // Typed keys read the same frames as their index.
+        assert_eq!(8, Container::MAX_WIDTH.index());
+        assert_eq!(11, StyleContainer::BACKGROUND_R.index());
+        assert_eq!(2, Transform::SCALE.index());
+
+        #[derive(Debug)]
+        struct Circle {
+            at: MovementType,
+            ease: Ease,
+            radius: Option<f32>,
+            thickness: Option<f32>,
+            is_eager: bool,
+        }
+
+        properties! {
+            Circle(circle) {
+                /// The radius.
+                RADIUS =>
+                    circle.radius.map(|r| Frame::eager(circle.at, r, circle.ease)),
+                    Some(Frame::lazy(circle.at, 0., circle.ease));
+                /// The thickness of the outline.
+                THICKNESS =>
+                    circle.thickness.map(|t| Frame::eager(circle.at, t, circle.ease)),
+                    Some(Frame::lazy(circle.at, 1., circle.ease));
+            }
+        }
+
+        let circle = |at: Duration, radius: f32| Circle {
+            at: at.into(),
+            ease: Linear::InOut.into(),
+            radius: Some(radius),
+            thickness: None,
+            is_eager: true,
+        };
+        let id = widget::Id::unique();
+        let start = Instant::now();
+        let mut timeline = Timeline::new();
+        let chain = Chain::new(
+            id.clone(),
+            Repeat::Never,
+            vec![
+                circle(Duration::ZERO, 0.).into(),
+                circle(Duration::from_secs(1), 10.).into(),
+            ],
+        );
+        let _ = timeline.set_chain(chain);
+        timeline.start_at(start);
+        timeline.now(start + Duration::from_millis(500));
+
+        assert_eq!(1, Circle::THICKNESS.index());
+        assert_eq!(5., timeline.get(&id, Circle::RADIUS).unwrap().value);
+        assert_eq!(
+            timeline.get(&id, 0).unwrap().value,
+            timeline.get(&id, Circle::RADIUS).unwrap().value
+        );
+        assert!(timeline.get(&id, Circle::THICKNESS).is_none());
+    }
+
+    #[test]
+    fn custom_keyframe() {
+        mod circle {
+            use crate::timeline::Frame;
+            use crate::{keyframe, properties};
+
+            keyframe! {
+                #[derive(Debug, Clone, Copy)]
+                pub struct Circle {
+                    radius: Option<f32>,
+                    thickness: f32 = 2.,
+                }
+            }
+
+            impl Circle {
+                pub fn radius(mut self, radius: f32) -> Self {
+                    self.radius = Some(radius);
+                    self
+                }
+            }
+
+            properties! {
+                Circle(circle) {
+                    RADIUS =>
+                        circle.radius.map(|r| Frame::eager(circle.at, r, circle.ease)),
+                        Some(Frame::lazy(circle.at, 0., circle.ease));
+                    THICKNESS =>
+                        Some(Frame::eager(circle.at, circle.thickness, circle.ease)),
+                        Some(Frame::lazy(circle.at, 2., circle.ease));
+                }
+            }
+        }
+        use circle::{Circle, Id};
+
+        let id = Id::unique();
+        let start = Instant::now();
+        let mut timeline = Timeline::new();
+        let animation = chain![
+            id,
+            Circle::lazy(Duration::ZERO),
+            Circle::new(Duration::from_secs(1)).radius(10.),
+        ]
+        .link(
+            Circle::new(Duration::from_secs(1))
+                .radius(20.)
+                .ease(Linear::InOut),
+        );
+        let _ = timeline.set_chain(animation);
+        timeline.start_at(start);
+
+        // Nothing was animating, so the lazy keyframe starts at its default.
+        let id = id.into();
+        timeline.now(start + Duration::from_millis(500));
+        assert_eq!(5., timeline.get(&id, Circle::RADIUS).unwrap().value);
+        assert_eq!(2., timeline.get(&id, Circle::THICKNESS).unwrap().value);
+        timeline.now(start + Duration::from_millis(1500));
+        assert_eq!(15., timeline.get(&id, Circle::RADIUS).unwrap().value);
+    }
+
+    #[test]
+    fn delay_and_hold() {
+        use crate::keyframes::Transform;
+        use crate::reexports::iced_core::{widget, Point};
+        use crate::track::Key;
+
+        let id = id::Transform::unique();
+        let start = Instant::now();
+        let mut timeline = Timeline::new();
+        let animation = chain![
+            id,
+            transform(Duration::ZERO).x(0.).y(0.),
+            transform(Duration::from_secs(1)).x(10.),
+        ]
+        // `y` was only set by the first keyframe, but is held too.
+        .hold(Duration::from_secs(1))
+        .link(transform(Duration::from_secs(1)).y(10.))
+        .delay(Duration::from_millis(500));
+        let _ = timeline.set_chain(animation);
+        timeline.start_at(start);
+
+        let id = id.into();
+        timeline.now(start + Duration::from_millis(250));
+        assert_eq!(0., timeline.get(&id, Transform::X).unwrap().value);
+        timeline.now(start + Duration::from_millis(1000));
+        assert_eq!(5., timeline.get(&id, Transform::X).unwrap().value);
+        timeline.now(start + Duration::from_millis(2000));
+        assert_eq!(10., timeline.get(&id, Transform::X).unwrap().value);
+        assert_eq!(0., timeline.get(&id, Transform::Y).unwrap().value);
+        timeline.now(start + Duration::from_millis(3000));
+        assert_eq!(5., timeline.get(&id, Transform::Y).unwrap().value);
+
+        // A hold before the first keyframe is a delay.
+        let id = widget::Id::unique();
+        let track = Track::new(id.clone())
+            .hold(Duration::from_secs(1))
+            .link(Key::new(Duration::ZERO, Point::ORIGIN))
+            .link(Key::new(Duration::from_secs(1), Point::new(10., 0.)))
+            .hold(Duration::from_secs(1))
+            .link(Key::new(Duration::from_secs(1), Point::new(10., 10.)));
+        let _ = timeline.set_chain(track);
+        timeline.start_at(start);
+        timeline.now(start + Duration::from_millis(500));
+        assert_eq!(Some(Point::ORIGIN), timeline.value(&id));
+        timeline.now(start + Duration::from_millis(1500));
+        assert_eq!(Some(Point::new(5., 0.)), timeline.value(&id));
+        timeline.now(start + Duration::from_millis(2500));
+        assert_eq!(Some(Point::new(10., 0.)), timeline.value(&id));
+        timeline.now(start + Duration::from_millis(3500));
+        assert_eq!(Some(Point::new(10., 5.)), timeline.value(&id));
+    }
+
+    #[test]
+    fn delay_adds_to_hold() {
+        use crate::keyframes::Transform;
+        use crate::reexports::iced_core::{widget, Point};
+        use crate::track::Key;
+
+        let start = Instant::now();
+        let mut timeline = Timeline::new();
+        let id = id::Transform::unique();
+        let hold_then_delay = chain![id]
+            .hold(Duration::from_secs(1))
+            .delay(Duration::from_millis(500))
+            .link(transform(Duration::ZERO).x(0.))
+            .link(transform(Duration::from_secs(1)).x(10.));
+        let other = id::Transform::unique();
+        let delay_then_hold = chain![other]
+            .delay(Duration::from_millis(500))
+            .hold(Duration::from_secs(1))
+            .link(transform(Duration::ZERO).x(0.))
+            .link(transform(Duration::from_secs(1)).x(10.));
+        let track = widget::Id::unique();
+        let _ = timeline
+            .set_chain(hold_then_delay)
+            .set_chain(delay_then_hold)
+            .set_chain(
+                Track::new(track.clone())
+                    .delay(Duration::from_millis(500))
+                    .hold(Duration::from_secs(1))
+                    .link(Key::new(Duration::ZERO, Point::ORIGIN))
+                    .link(Key::new(Duration::from_secs(1), Point::new(10., 0.)))
+                    .delay(Duration::from_millis(500)),
+            );
+        timeline.start_at(start);
+
+        // Both orders wait 1.5s before animating, and the track 2s.
+        let (id, other) = (id.into(), other.into());
+        timeline.now(start + Duration::from_millis(1500));
+        assert_eq!(0., timeline.get(&id, Transform::X).unwrap().value);
+        assert_eq!(0., timeline.get(&other, Transform::X).unwrap().value);
+        timeline.now(start + Duration::from_millis(2000));
+        assert_eq!(5., timeline.get(&id, Transform::X).unwrap().value);
+        assert_eq!(5., timeline.get(&other, Transform::X).unwrap().value);
+        assert_eq!(Some(Point::ORIGIN), timeline.value(&track));
+        timeline.now(start + Duration::from_millis(2500));
+        assert_eq!(Some(Point::new(5., 0.)), timeline.value(&track));
+    }
 }
@@ -0,0 +1,268 @@
+use crate::reexports::iced_core::widget;
+use crate::timeline::{Chain, Timeline};
+
+/// Where an item of [`Presence`] is in its lifetime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    /// The item was inserted, and its enter animation is playing.
+    Entering,
+    /// The enter animation has finished, or there was none.
+    Present,
+    /// The item was removed, but is kept until its exit animation finishes.
+    Exiting,
+}
+
+#[derive(Debug)]
+struct Item<T> {
+    id: widget::Id,
+    value: T,
+    phase: Phase,
+}
+
+/// A list of items that animate in when inserted, and animate out before
+/// they are dropped.
+///
+/// Keep a `Presence` in your app's state instead of a `Vec`, and `view()`
+/// everything it contains. Removed items stay in the list, in [`Phase::Exiting`],
+/// until their exit chain completes. Call [`Presence::update`] after
+/// [`Timeline::now`] to drop them.
+///
+/// Each item is keyed by the widget Id of its animation chains.
+#[derive(Debug)]
+pub struct Presence<T> {
+    items: Vec<Item<T>>,
+}
+
+impl<T> Default for Presence<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Presence<T> {
+    /// Create an empty [`Presence`].
+    #[must_use]
+    pub fn new() -> Self {
+        Presence { items: Vec::new() }
+    }
+
+    /// Add an item to the end of the list, and play its enter chain.
+    /// An item with the same Id, in any phase, is replaced and moved to the end,
+    /// and enters again.
+    pub fn push(&mut self, timeline: &mut Timeline, value: T, enter: impl Into<Chain>) {
+        let index = self.items.len();
+        self.insert(timeline, index, value, enter);
+    }
+
+    /// Add an item at `index`, and play its enter chain.
+    /// An item with the same Id, in any phase, is replaced and moved to `index`,
+    /// and enters again.
+    ///
+    /// `index` is where the item ends up. An item with the same Id is removed
+    /// before the item is inserted, so `index` counts the list without it.
+    /// An `index` past the end adds the item to the end.
+    pub fn insert(
+        &mut self,
+        timeline: &mut Timeline,
+        index: usize,
+        value: T,
+        enter: impl Into<Chain>,
+    ) {
+        let enter = enter.into();
+        let item = Item {
+            id: enter.id.clone(),
+            value,
+            phase: Phase::Entering,
+        };
+        if let Some(existing) = self.position(&item.id) {
+            let _ = self.items.remove(existing);
+        }
+        self.items.insert(index.min(self.items.len()), item);
+        let _ = timeline.set_chain(enter);
+    }
+
+    /// Add an item that is already present, without an enter animation.
+    /// An item with the same Id, in any phase, is replaced and moved to the end.
+    pub fn push_present(&mut self, id: impl Into<widget::Id>, value: T) {
+        let id = id.into();
+        if let Some(existing) = self.position(&id) {
+            let _ = self.items.remove(existing);
+        }
+        self.items.push(Item {
+            id,
+            value,
+            phase: Phase::Present,
+        });
+    }
+
+    /// Start removing the item with the same Id as the exit chain.
+    /// The item is kept until the chain completes.
+    /// Does nothing if there is no such item.
+    pub fn remove(&mut self, timeline: &mut Timeline, exit: impl Into<Chain>) {
+        let exit = exit.into();
+        if let Some(index) = self.position(&exit.id) {
+            self.items[index].phase = Phase::Exiting;
+            let _ = timeline.set_chain(exit);
+        }
+    }
+
+    /// Remove an item right away, without an exit animation.
+    pub fn remove_now(&mut self, timeline: &mut Timeline, id: impl Into<widget::Id>) -> Option<T> {
+        let id = id.into();
+        let index = self.position(&id)?;
+        let _ = timeline.clear_chain(id);
+        Some(self.items.remove(index).value)
+    }
+
+    /// Use this in your `update()`, after [`Timeline::now`].
+    /// Marks items that have finished entering as present, and drops items that
+    /// have finished exiting. Their animations are cleared from the timeline.
+    /// Returns the dropped items.
+    pub fn update(&mut self, timeline: &mut Timeline) -> Vec<T> {
+        let done = |id: &widget::Id| !timeline.is_running(id) && !timeline.is_paused(id);
+        let mut exited = Vec::new();
+        let mut kept = Vec::with_capacity(self.items.len());
+        for mut item in self.items.drain(..) {
+            match item.phase {
+                Phase::Entering if done(&item.id) => {
+                    item.phase = Phase::Present;
+                    kept.push(item);
+                }
+                Phase::Exiting if done(&item.id) => exited.push(item),
+                _ => kept.push(item),
+            }
+        }
+        self.items = kept;
+
+        exited
+            .into_iter()
+            .map(|item| {
+                let _ = timeline.clear_chain(item.id);
+                item.value
+            })
+            .collect()
+    }
+
+    /// The phase of the item with this Id, if there is one.
+    #[must_use]
+    pub fn phase(&self, id: &widget::Id) -> Option<Phase> {
+        self.position(id).map(|index| self.items[index].phase)
+    }
+
+    /// Get the item with this Id, if there is one.
+    #[must_use]
+    pub fn get(&self, id: &widget::Id) -> Option<&T> {
+        self.position(id).map(|index| &self.items[index].value)
+    }
+
+    /// Get the item with this Id mutably, if there is one.
+    pub fn get_mut(&mut self, id: &widget::Id) -> Option<&mut T> {
+        self.position(id).map(|index| &mut self.items[index].value)
+    }
+
+    /// All items in order, including the ones that are still exiting.
+    /// Use this in your `view()`.
+    pub fn iter(&self) -> impl Iterator<Item = (&T, Phase)> + '_ {
+        self.items.iter().map(|item| (&item.value, item.phase))
+    }
+
+    /// The number of items, including the ones that are still exiting.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Are there no items, not even exiting ones?
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    fn position(&self, id: &widget::Id) -> Option<usize> {
+        self.items.iter().position(|item| item.id == *id)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::keyframes::Transform;
+    use crate::{chain, id, lazy, transform, Duration, Instant};
+
+    #[test]
+    fn presence() {
+        let toast = id::Transform::new("toast");
+        let other = id::Transform::new("other");
+        let start = Instant::now();
+        let mut timeline = Timeline::new();
+        let mut toasts = Presence::new();
+
+        let enter = |id: &id::Transform| {
+            chain![
+                id.clone(),
+                transform(Duration::ZERO).scale(0.),
+                transform(Duration::from_secs(1)).scale(1.),
+            ]
+        };
+        let exit = |id: &id::Transform| {
+            chain![
+                id.clone(),
+                lazy::transform(Duration::ZERO),
+                transform(Duration::from_secs(1)).scale(0.),
+            ]
+        };
+
+        toasts.push(&mut timeline, "toast", enter(&toast));
+        toasts.push_present(other.clone(), "other");
+        timeline.start_at(start);
+        let toast = toast.into();
+        assert_eq!(Some(Phase::Entering), toasts.phase(&toast));
+
+        timeline.now(start + Duration::from_secs(1));
+        assert!(toasts.update(&mut timeline).is_empty());
+        assert_eq!(Some(Phase::Present), toasts.phase(&toast));
+
+        toasts.remove(&mut timeline, exit(&id::Transform::new("toast")));
+        timeline.start_at(start + Duration::from_secs(2));
+        timeline.now(start + Duration::from_millis(2500));
+        assert!(toasts.update(&mut timeline).is_empty());
+        assert_eq!(Some(Phase::Exiting), toasts.phase(&toast));
+        assert_eq!(0.5, timeline.get(&toast, Transform::SCALE).unwrap().value);
+        assert_eq!(2, toasts.len());
+
+        timeline.now(start + Duration::from_secs(3));
+        assert_eq!(vec!["toast"], toasts.update(&mut timeline));
+        assert_eq!(None, toasts.phase(&toast));
+        assert!(timeline.get(&toast, Transform::SCALE).is_none());
+        assert_eq!(
+            vec![(&"other", Phase::Present)],
+            toasts.iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn reinsert() {
+        let enter = |name| {
+            chain![
+                id::Transform::new(name),
+                transform(Duration::from_secs(1)).scale(1.),
+            ]
+        };
+        let mut timeline = Timeline::new();
+        let mut items = Presence::new();
+        for name in ["a", "b", "c"] {
+            items.push(&mut timeline, name, enter(name));
+        }
+
+        // The index is where the item ends up, once its old entry is gone.
+        items.insert(&mut timeline, 1, "a", enter("a"));
+        let names =
+            |items: &Presence<&'static str>| items.iter().map(|(n, _)| *n).collect::<Vec<_>>();
+        assert_eq!(vec!["b", "a", "c"], names(&items));
+
+        // Pushing an item that is already there moves it to the end.
+        items.push(&mut timeline, "b", enter("b"));
+        assert_eq!(vec!["a", "c", "b"], names(&items));
+        assert_eq!(3, items.len());
+    }
+}
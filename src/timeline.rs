@@ -16,11 +16,15 @@ mod imports {
 
 use imports::{widget, Duration, Instant, Subscription};
 
+use std::any::Any;
 use std::cmp::Ordering;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::marker::PhantomData;
+use std::sync::Arc;
 
+use crate::group::{Layout, Schedule};
 use crate::keyframes::Repeat;
-use crate::{lerp, Ease, MovementType, Tween};
+use crate::{lerp, Animatable, ColorSpace, Ease, Linear, MovementType, Spring, Tween};
 
 /// This holds all the data for your animations.
 /// tracks: this holds all data for active animations
@@ -35,6 +39,14 @@ pub struct Timeline {
     // Global animation interp value. Use `timeline.now(instant)`, where instant is the value
     // passed from the `timeline.as_subscription` value.
     now: Option<Instant>,
+    // Global playback speed, multiplied with each animation's own speed.
+    speed: f32,
+    // The color space colors are blended in, unless a keyframe sets its own.
+    color_space: ColorSpace,
+    // Events that have happened since the last `drain_events`.
+    events: Vec<AnimationEvent>,
+    // Groups of chains, by the Id of their outermost group.
+    groups: HashMap<widget::Id, GroupState>,
 }
 
 impl std::default::Default for Timeline {
@@ -53,19 +65,332 @@ pub struct Chain {
     /// Should we loop this animation? This field decides that.
     pub repeat: Repeat,
     links: Vec<Vec<Option<Frame>>>,
+    // The values of a typed [`crate::Track`], if this chain is one.
+    values: Option<Arc<dyn TrackValues>>,
+    // The [`crate::Group`] this chain is played in, if any.
+    group: Option<Arc<Layout>>,
 }
 
 impl Chain {
     /// Create a new chain.
     pub fn new(id: widget::Id, repeat: Repeat, links: impl Into<Vec<Vec<Option<Frame>>>>) -> Self {
         let links = links.into();
-        Chain { id, repeat, links }
+        Chain {
+            id,
+            repeat,
+            links,
+            values: None,
+            group: None,
+        }
+    }
+
+    // A chain for a typed [`crate::Track`]. Its only column holds the
+    // index into `values` that each keyframe animates to.
+    pub(crate) fn with_values(
+        id: widget::Id,
+        repeat: Repeat,
+        links: Vec<Vec<Option<Frame>>>,
+        values: Arc<dyn TrackValues>,
+    ) -> Self {
+        Chain {
+            id,
+            repeat,
+            links,
+            values: Some(values),
+            group: None,
+        }
+    }
+
+    // A chain that is scheduled along with the rest of its group.
+    pub(crate) fn in_group(mut self, group: Arc<Layout>) -> Self {
+        self.group = Some(group);
+        self
+    }
+
+    /// Play the chain backwards, from the last keyframe to the first.
+    /// The time (and ease) into each keyframe is moved along with it,
+    /// so the reversed animation is the mirror image of the original.
+    #[must_use]
+    pub fn reverse(mut self) -> Self {
+        let original = self.links.clone();
+        // The timing of the first frame in each row. Frames in a row may each have
+        // their own timing, so this is only for properties the next row doesn't set.
+        let timings: Vec<Option<(MovementType, Ease)>> = original
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .flatten()
+                    .next()
+                    .map(|frame| (frame.movement_type(), frame.ease()))
+            })
+            .collect();
+
+        self.links.reverse();
+        let rows = self.links.len();
+        for (j, row) in self.links.iter_mut().enumerate() {
+            // The time into this keyframe, is the time out of it in the original.
+            let i = rows - 1 - j;
+            let Some((movement_type, row_ease)) = timings.get(i + 1).copied().flatten() else {
+                continue;
+            };
+            for (column, frame) in row.iter_mut().enumerate() {
+                if let Some(frame) = frame {
+                    let (movement_type, ease) = original[i + 1][column]
+                        .map_or((movement_type, row_ease), |f| (f.movement_type(), f.ease()));
+                    *frame = frame.with_timing(movement_type, ease.reverse());
+                }
+            }
+        }
+
+        // The first keyframe in the reversed chain has nothing to animate from.
+        if let Some(first) = self.links.first_mut() {
+            for frame in first.iter_mut().flatten() {
+                *frame = frame.with_timing(Duration::ZERO.into(), frame.ease());
+            }
+        }
+        self
+    }
+
+    /// Link another keyframe's frames, one for each property.
+    #[must_use]
+    pub fn link(mut self, frames: impl Into<Vec<Option<Frame>>>) -> Self {
+        self.links.push(frames.into());
+        self
+    }
+
+    /// Link `links`, with `holds` of how long to hold after how many links,
+    /// then reverse the chain if asked and `delay` it. How the chains of
+    /// [`crate::keyframe!`] and [`crate::Track`] are converted.
+    #[doc(hidden)]
+    #[must_use]
+    pub fn build<L: Into<Vec<Option<Frame>>>>(
+        mut self,
+        links: impl IntoIterator<Item = L>,
+        holds: Vec<(usize, Duration)>,
+        delay: Duration,
+        reverse: bool,
+    ) -> Self {
+        let mut holds = holds.into_iter().peekable();
+        for (i, link) in links.into_iter().enumerate() {
+            while let Some((_, duration)) = holds.next_if(|(at, _)| *at == i) {
+                self = self.hold(duration);
+            }
+            self = self.link(link);
+        }
+        for (_, duration) in holds {
+            self = self.hold(duration);
+        }
+        if reverse {
+            self = self.reverse();
+        }
+        self.delay(delay)
+    }
+
+    /// Hold every property at the value of the last keyframe for `duration`,
+    /// before animating to the next keyframe. Does nothing if the chain is empty.
+    #[must_use]
+    pub fn hold(mut self, duration: Duration) -> Self {
+        let Some(columns) = self.links.first().map(Vec::len) else {
+            return self;
+        };
+        // Properties hold the value of the last keyframe that set them.
+        let held = (0..columns)
+            .map(|column| {
+                self.links
+                    .iter()
+                    .rev()
+                    .find_map(|row| row.get(column).copied().flatten())
+                    .map(|frame| {
+                        // The value is not moving by the end of the hold.
+                        let frame = match frame {
+                            Frame::LazyVelocity(movement_type, default, ease) => {
+                                Frame::Lazy(movement_type, default, ease)
+                            }
+                            frame => frame,
+                        };
+                        frame.with_timing(duration.into(), Linear::InOut.into())
+                    })
+            })
+            .collect();
+        self.links.push(held);
+        self
+    }
+
+    /// Wait `delay` before playing the chain, by holding the first keyframe.
+    /// The delay is part of the chain, so it is repeated if the chain loops.
+    #[must_use]
+    pub fn delay(mut self, delay: Duration) -> Self {
+        if delay.is_zero() {
+            return self;
+        }
+        if let Some(first) = self.links.first().cloned() {
+            let held = &mut self.links[0];
+            for frame in held.iter_mut().flatten() {
+                *frame = frame.with_timing(delay.into(), Linear::InOut.into());
+            }
+            self.links.insert(0, first);
+        }
+        self
+    }
+}
+
+// The type erased values of a typed [`crate::Track`].
+pub(crate) trait TrackValues: std::fmt::Debug + Send + Sync {
+    fn as_any(&self) -> &dyn Any;
+
+    // Fill in lazy keyframes with the value the previous animation is at.
+    fn resolve(&self, previous: Option<(&dyn TrackValues, Interped)>) -> Arc<dyn TrackValues>;
+
+    // How far apart two of the values are, for `Speed` based timing.
+    fn distance(&self, from: usize, to: usize) -> f32;
+}
+
+/// Anything that can be added to the [`Timeline`] with `set_chain`.
+/// Either a single chain, or a group of chains like [`Stagger`].
+pub trait IntoChains {
+    /// Convert into the chains to be added to the [`Timeline`].
+    fn into_chains(self) -> Vec<Chain>;
+}
+
+impl<T: Into<Chain>> IntoChains for T {
+    fn into_chains(self) -> Vec<Chain> {
+        vec![self.into()]
+    }
+}
+
+/// Play the same animation on many widgets, one after another.
+/// Great for list entrance animations.
+///
+/// Each Id gets a copy of the template chain, delayed by its place in the list.
+/// The template chain's own Id is not used.
+/// ```ignore
+/// let rows = vec![id::Container::new("a"), id::Container::new("b")];
+/// let template = chain![
+///     id::Container::unique(),
+///     container(Duration::ZERO).height(0.),
+///     container(Duration::from_millis(300)).height(40.),
+/// ];
+/// self.timeline
+///     .set_chain(Stagger::new(rows, template).delay(Duration::from_millis(50)))
+///     .start();
+/// ```
+#[derive(Debug, Clone)]
+pub struct Stagger {
+    ids: Vec<widget::Id>,
+    template: Chain,
+    delay: Duration,
+    curve: Option<Ease>,
+}
+
+impl Stagger {
+    /// Create a new stagger, with no delay between each Id.
+    pub fn new<I: Into<widget::Id>>(
+        ids: impl IntoIterator<Item = I>,
+        template: impl Into<Chain>,
+    ) -> Self {
+        Stagger {
+            ids: ids.into_iter().map(Into::into).collect(),
+            template: template.into(),
+            delay: Duration::ZERO,
+            curve: None,
+        }
+    }
+
+    /// The delay between each Id starting its animation.
+    #[must_use]
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self.curve = None;
+        self
+    }
+
+    /// Spread the start of each Id's animation over `total` time, following
+    /// the ease. The first Id starts immediately, the last after `total`.
+    #[must_use]
+    pub fn delay_curve(mut self, total: Duration, ease: impl Into<Ease>) -> Self {
+        self.delay = total;
+        self.curve = Some(ease.into());
+        self
+    }
+}
+
+impl IntoChains for Vec<Chain> {
+    fn into_chains(self) -> Vec<Chain> {
+        self
+    }
+}
+
+impl IntoChains for Stagger {
+    fn into_chains(self) -> Vec<Chain> {
+        let count = self.ids.len();
+        self.ids
+            .into_iter()
+            .enumerate()
+            .map(|(i, id)| {
+                let delay = match self.curve {
+                    Some(ease) if count > 1 => {
+                        let percent = i as f32 / (count - 1) as f32;
+                        self.delay.mul_f32(ease.tween(percent).max(0.))
+                    }
+                    Some(_) => Duration::ZERO,
+                    None => self.delay * i as u32,
+                };
+                let mut chain = self.template.clone().delay(delay);
+                chain.id = id;
+                chain
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone)]
+struct PendingChain {
+    repeat: Repeat,
+    links: Vec<Vec<Option<Frame>>>,
+    values: Option<Arc<dyn TrackValues>>,
+    pause: Pause,
+    speed: f32,
+    seek: Option<Seek>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Seek {
+    Time(Duration),
+    Fraction(f32),
+}
+
+// A [`crate::Group`] on the timeline.
+#[derive(Debug, Clone)]
+struct GroupState {
+    layout: Arc<Layout>,
+    // `None` until the group is started, and its chains are scheduled.
+    schedule: Option<Schedule>,
+    // A seek made before the group started, and the Id of the (nested) group it is for.
+    seek: Option<(widget::Id, Seek)>,
+    // Chains that were replaced or cleared on their own, and are no longer in the group.
+    left: HashSet<widget::Id>,
+}
+
+impl GroupState {
+    // The chains still in the (nested) group `layout`.
+    fn chains(&self, layout: &Layout) -> Vec<widget::Id> {
+        let mut chains = layout.chains();
+        chains.retain(|chain| !self.left.contains(chain));
+        chains
     }
 }
 
+// The chains of a (nested) group, and where it is in its outermost group.
+struct GroupMembers {
+    root: widget::Id,
+    chains: Vec<widget::Id>,
+    // When the group starts and ends, once it is scheduled.
+    span: Option<(Duration, Duration)>,
+}
+
 #[derive(Debug, Clone)]
 enum Pending {
-    Chain(Repeat, Vec<Vec<Option<Frame>>>, Pause),
+    Chain(PendingChain),
     Pause,
     Resume,
     PauseAll,
@@ -79,12 +404,24 @@ enum Pending {
 /// like "animate to width 10".
 /// A `Frame::Lazy` is for continueing a previous animation, either midway through
 /// the animation, or even after the animation was completed.
+/// A `Frame::LazyVelocity` is a `Frame::Lazy` that also continues the previous
+/// animation's velocity, blending it into the next link of the chain.
+/// A `Frame::Step` is for values that can't be blended, like an enum. The value
+/// switches halfway through the link, but [`Interped`]'s `previous`, `next` and
+/// `percent` still describe the link, so a widget can blend the values itself.
+/// A `Frame::LazyStep` is a `Frame::Step` that continues from the previous animation.
 #[derive(Debug, Clone, Copy)]
 pub enum Frame {
     /// Keyframe time, !!VALUE AT TIME!!, ease type into value
     Eager(MovementType, f32, Ease),
     /// Keyframe time, !!DEFAULT FALLBACK VALUE!!, ease type into value
     Lazy(MovementType, f32, Ease),
+    /// Keyframe time, !!DEFAULT FALLBACK VALUE!!, ease type into value
+    LazyVelocity(MovementType, f32, Ease),
+    /// Keyframe time, !!VALUE AT TIME!!, ease type into value
+    Step(MovementType, f32, Ease),
+    /// Keyframe time, !!DEFAULT FALLBACK VALUE!!, ease type into value
+    LazyStep(MovementType, f32, Ease),
 }
 
 impl Frame {
@@ -100,33 +437,76 @@ impl Frame {
         Frame::Lazy(movement_type, default, ease)
     }
 
+    /// Create a Lazy Frame that continues the velocity of the previous animation.
+    pub fn lazy_velocity(movement_type: impl Into<MovementType>, default: f32, ease: Ease) -> Self {
+        let movement_type = movement_type.into();
+        Frame::LazyVelocity(movement_type, default, ease)
+    }
+
+    /// Create a Step Frame, for a value that is not blended.
+    pub fn step(movement_type: impl Into<MovementType>, value: f32, ease: Ease) -> Self {
+        let movement_type = movement_type.into();
+        Frame::Step(movement_type, value, ease)
+    }
+
+    /// Create a Lazy Step Frame, for a value that is not blended.
+    pub fn lazy_step(movement_type: impl Into<MovementType>, default: f32, ease: Ease) -> Self {
+        let movement_type = movement_type.into();
+        Frame::LazyStep(movement_type, default, ease)
+    }
+
+    /// Converts a `Frame::Lazy` into a `Frame::LazyVelocity` if `blend` is true.
+    /// Used by keyframes to support `blend_velocity`.
+    #[must_use]
+    pub fn with_velocity(self, blend: bool) -> Self {
+        match self {
+            Frame::Lazy(movement_type, default, ease) if blend => {
+                Frame::LazyVelocity(movement_type, default, ease)
+            }
+            frame => frame,
+        }
+    }
+
     /// You almost certainly do not need this function.
     /// Used in `timeline::start` to guarentee that we have the same
     /// time of an animation, not the API convinient [`MovementType`].
     #[must_use]
     pub fn to_subframe(self, time: Instant) -> SubFrame {
-        let (value, ease) = match self {
-            Frame::Eager(_movement_type, value, ease) => (value, ease),
+        let (movement_type, value, ease, step) = match self {
+            Frame::Eager(movement_type, value, ease) => (movement_type, value, ease, false),
+            Frame::Step(movement_type, value, ease) => (movement_type, value, ease, true),
             _ => panic!("Call 'to_eager' first"),
         };
 
-        SubFrame::new(time, value, ease)
+        let mut subframe = SubFrame::new(time, value, ease);
+        if let MovementType::Spring(spring) = movement_type {
+            subframe.spring = Some(spring);
+        }
+        subframe.step = step;
+        subframe
     }
 
     /// You almost certainly do not need this function.
     /// Converts a Lazy [`Frame`] to an Eager [`Frame`].
     pub fn to_eager(&mut self, timeline: &Timeline, id: &widget::Id, index: usize) {
-        *self = if let Frame::Lazy(movement_type, default, ease) = *self {
-            let value = timeline.get(id, index).map_or(default, |i| i.value);
-            Frame::Eager(movement_type, value, ease)
-        } else {
-            *self
+        *self = match *self {
+            Frame::Lazy(movement_type, default, ease)
+            | Frame::LazyVelocity(movement_type, default, ease) => {
+                let value = timeline.get(id, index).map_or(default, |i| i.value);
+                Frame::Eager(movement_type, value, ease)
+            }
+            // Steps are never between two values, so this is always one of them.
+            Frame::LazyStep(movement_type, default, ease) => {
+                let value = timeline.get(id, index).map_or(default, |i| i.value);
+                Frame::Step(movement_type, value, ease)
+            }
+            Frame::Eager(..) | Frame::Step(..) => *self,
         }
     }
 
     fn get_value(&self) -> f32 {
         match self {
-            Frame::Eager(_, value, _) => *value,
+            Frame::Eager(_, value, _) | Frame::Step(_, value, _) => *value,
             _ => panic!("call 'to_eager' first"),
         }
     }
@@ -135,14 +515,86 @@ impl Frame {
     /// Get the duration of a [`Frame`]
     #[must_use]
     pub fn get_duration(self, previous: &Self) -> Duration {
+        self.get_duration_with_velocity(previous, 0.)
+    }
+
+    // Like `get_duration`, but springs take into account the velocity
+    // the previous frame was moving at.
+    fn get_duration_with_velocity(self, previous: &Self, velocity: f32) -> Duration {
         match self {
             Frame::Eager(movement_type, value, _ease) => match movement_type {
                 MovementType::Duration(duration) => duration,
                 MovementType::Speed(speed) => speed.calc_duration(previous.get_value(), value),
+                MovementType::Spring(spring) => {
+                    spring.calc_duration(previous.get_value(), value, velocity)
+                }
+            },
+            // A step has no distance to travel, only a time to switch at.
+            Frame::Step(movement_type, _value, _ease) => match movement_type {
+                MovementType::Duration(duration) => duration,
+                MovementType::Speed(_) | MovementType::Spring(_) => Duration::ZERO,
             },
             _ => panic!("Call 'to_eager' first"),
         }
     }
+
+    fn movement_type(&self) -> MovementType {
+        match self {
+            Frame::Eager(movement_type, _, _)
+            | Frame::Lazy(movement_type, _, _)
+            | Frame::LazyVelocity(movement_type, _, _)
+            | Frame::Step(movement_type, _, _)
+            | Frame::LazyStep(movement_type, _, _) => *movement_type,
+        }
+    }
+
+    fn ease(&self) -> Ease {
+        match self {
+            Frame::Eager(_, _, ease)
+            | Frame::Lazy(_, _, ease)
+            | Frame::LazyVelocity(_, _, ease)
+            | Frame::Step(_, _, ease)
+            | Frame::LazyStep(_, _, ease) => *ease,
+        }
+    }
+
+    fn with_timing(self, movement_type: MovementType, ease: Ease) -> Self {
+        match self {
+            Frame::Eager(_, value, _) => Frame::Eager(movement_type, value, ease),
+            Frame::Lazy(_, value, _) => Frame::Lazy(movement_type, value, ease),
+            Frame::LazyVelocity(_, value, _) => Frame::LazyVelocity(movement_type, value, ease),
+            Frame::Step(_, value, _) => Frame::Step(movement_type, value, ease),
+            Frame::LazyStep(_, value, _) => Frame::LazyStep(movement_type, value, ease),
+        }
+    }
+
+    // The velocity this frame carries into the next link of the chain.
+    // Running springs always carry their velocity, otherwise only a
+    // `Frame::LazyVelocity` does.
+    fn carried_velocity(&self, timeline: &Timeline, id: &widget::Id, index: usize) -> f32 {
+        match self {
+            Frame::Eager(..) | Frame::Step(..) | Frame::LazyStep(..) => 0.,
+            Frame::Lazy(..) => timeline
+                .interp(id, index)
+                .filter(|(_interped, is_spring)| *is_spring)
+                .map_or(0., |(interped, _is_spring)| interped.velocity),
+            Frame::LazyVelocity(..) => timeline.get(id, index).map_or(0., |i| i.velocity),
+        }
+    }
+}
+
+/// Events emitted by the [`Timeline`] as animations progress.
+/// Collect them with [`Timeline::drain_events`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AnimationEvent {
+    /// The animation was started.
+    Started(widget::Id),
+    /// The animation finished a loop, and started the next one.
+    Looped(widget::Id),
+    /// The animation played until completion.
+    Completed(widget::Id),
+    /// The animation was replaced, or cleared, before it completed.
+    Interrupted(widget::Id),
 }
 
 /// The metadata of an animation. Used by [`Timeline`].
@@ -157,8 +609,22 @@ pub struct Meta {
     pub end: Instant,
     /// The length of time the animation will last
     pub length: Duration,
+    /// How long the first frame is held before the animation plays.
+    /// Held once, not on every repeat. Starts the chains of a [`crate::Group`] in turn.
+    pub delay: Duration,
     /// Is the animation paused? This decides that.
     pub pause: Pause,
+    /// The playback speed set for this animation. 1.0 is normal speed,
+    /// negative values play the animation in reverse.
+    pub speed: f32,
+    /// The playback speed actually used. The animation's speed multiplied
+    /// by the [`Timeline`]'s global speed.
+    pub rate: f32,
+    /// The real instant, and the matching instant into the animation, that the
+    /// animation last changed rate (or was resumed) at. Keeps the animation continuous.
+    pub anchor: (Instant, Instant),
+    // The values of a typed [`crate::Track`], if the animation is one.
+    pub(crate) values: Option<Arc<dyn TrackValues>>,
 }
 
 impl Meta {
@@ -176,7 +642,165 @@ impl Meta {
             start,
             end,
             length,
+            delay: Duration::ZERO,
             pause,
+            speed: 1.0,
+            rate: 1.0,
+            anchor: (start, start),
+            values: None,
+        }
+    }
+
+    /// The instant into the animation at `now`.
+    /// Accounts for pauses, playback rate and repeats.
+    #[must_use]
+    pub fn time(&self, now: Instant) -> Instant {
+        let elapsed = self.elapsed(now) - self.delay.as_secs_f64();
+        if elapsed < 0. {
+            return self.start;
+        }
+        let length = self.length.as_secs_f64();
+        let offset = match self.repeat {
+            _ if length <= 0. => elapsed,
+            Repeat::Never | Repeat::Forever => elapsed,
+            Repeat::Times(times) => {
+                if elapsed >= length * f64::from(times) {
+                    length
+                } else {
+                    elapsed.rem_euclid(length)
+                }
+            }
+            Repeat::PingPong => {
+                if elapsed <= length {
+                    elapsed
+                } else {
+                    2. * length - elapsed
+                }
+            }
+        };
+        self.start + Duration::from_secs_f64(offset)
+    }
+
+    // Seconds into the animation at `now`, counting the delay, without folding
+    // repeats back into the first loop. Animations that repeat forever are only
+    // folded back into their first period.
+    fn elapsed(&self, now: Instant) -> f64 {
+        let elapsed = self.raw_elapsed(now);
+        let delay = self.delay.as_secs_f64();
+        let length = self.length.as_secs_f64();
+        match (self.total_length(), self.repeat) {
+            (Some(total), _) => elapsed.clamp(0., total),
+            (None, _) if length <= 0. || elapsed <= delay => elapsed.clamp(0., delay),
+            (None, Repeat::PingPong) => delay + (elapsed - delay).rem_euclid(2. * length),
+            (None, _) => delay + (elapsed - delay).rem_euclid(length),
+        }
+    }
+
+    // Seconds into the animation at `now`, without any folding or clamping.
+    fn raw_elapsed(&self, now: Instant) -> f64 {
+        match self.pause {
+            Pause::Paused(time) => signed_secs(time, self.start),
+            Pause::NoPause => {
+                let (real, time) = self.anchor;
+                signed_secs(time, self.start) + signed_secs(now, real) * f64::from(self.rate)
+            }
+        }
+    }
+
+    /// Has the animation played until completion?
+    /// Animations that repeat forever never finish.
+    #[must_use]
+    pub fn is_finished(&self, now: Instant) -> bool {
+        let Some(total) = self.total_length() else {
+            return false;
+        };
+        let elapsed = self.raw_elapsed(now);
+        (self.rate > 0. && elapsed >= total) || (self.rate < 0. && elapsed <= 0.)
+    }
+
+    // Fraction (0.0 -> 1.0) of the animation played at `now`.
+    fn progress(&self, now: Instant) -> f32 {
+        let length = self.length.as_secs_f64();
+        let fraction = match self.total_length() {
+            Some(total) if total > 0. => self.elapsed(now) / total,
+            Some(_) => 1.,
+            None if length > 0. => signed_secs(self.time(now), self.start) / length,
+            None => 0.,
+        };
+        fraction.clamp(0., 1.) as f32
+    }
+
+    // Real time left until the animation completes, at the current rate.
+    fn remaining(&self, now: Instant) -> Option<Duration> {
+        let total = self.total_length()?;
+        let elapsed = self.elapsed(now);
+        let remaining = if self.rate > 0. {
+            (total - elapsed) / f64::from(self.rate)
+        } else if self.rate < 0. {
+            elapsed / f64::from(-self.rate)
+        } else {
+            return None;
+        };
+        Some(Duration::from_secs_f64(remaining.max(0.)))
+    }
+
+    // How many times the animation has looped by `now`.
+    fn loop_count(&self, now: Instant) -> u32 {
+        let length = self.length.as_secs_f64();
+        if length <= 0. {
+            return 0;
+        }
+        let elapsed = self.raw_elapsed(now) - self.delay.as_secs_f64();
+        let loops = (elapsed.max(0.) / length).floor() as u32;
+        match self.repeat {
+            Repeat::Never => 0,
+            Repeat::Times(times) => loops.min(times.saturating_sub(1)),
+            Repeat::Forever | Repeat::PingPong => loops,
+        }
+    }
+
+    // How many times the animation looped between `previous` and `now`,
+    // and if it completed.
+    fn progressed(&self, previous: Instant, now: Instant) -> (u32, bool) {
+        let length = self.length.as_secs_f64();
+        if !self.pause.is_playing() || self.rate == 0. || length <= 0. {
+            return (0, false);
+        }
+
+        let (mut from, mut to) = (self.raw_elapsed(previous), self.raw_elapsed(now));
+        let completed = if let Some(total) = self.total_length() {
+            let completed = if self.rate > 0. {
+                from < total && to >= total
+            } else {
+                from > 0. && to <= 0.
+            };
+            from = from.clamp(0., total);
+            to = to.clamp(0., total);
+            completed
+        } else {
+            false
+        };
+
+        // Count the loop boundaries crossed, the boundaries the animation
+        // completes at are not loops. Nothing loops during the delay.
+        let delay = self.delay.as_secs_f64();
+        let (from, to) = ((from - delay).max(0.), (to - delay).max(0.));
+        let loops = if self.rate > 0. {
+            (to / length).floor() - (from / length).floor() - if completed { 1. } else { 0. }
+        } else {
+            (from / length).ceil() - (to / length).ceil().max(1.)
+        };
+        (loops.max(0.) as u32, completed)
+    }
+
+    // The total length of time the animation plays for, delay included,
+    // or `None` if it plays forever.
+    fn total_length(&self) -> Option<f64> {
+        let delay = self.delay.as_secs_f64();
+        match self.repeat {
+            Repeat::Never => Some(delay + self.length.as_secs_f64()),
+            Repeat::Times(times) => Some(delay + self.length.as_secs_f64() * f64::from(times)),
+            Repeat::Forever | Repeat::PingPong => None,
         }
     }
 
@@ -184,10 +808,8 @@ impl Meta {
     /// If you are an end user of Cosmic Time, you do not want this.
     /// You want the `pause` function on [`Timeline`].
     pub fn pause(&mut self, now: Instant) {
-        if let Pause::Resumed(delay) = self.pause {
-            self.pause = Pause::Paused(relative_time(&now.checked_sub(delay).unwrap(), self));
-        } else {
-            self.pause = Pause::Paused(relative_time(&now, self));
+        if self.pause.is_playing() {
+            self.pause = Pause::Paused(self.start + Duration::from_secs_f64(self.elapsed(now)));
         }
     }
 
@@ -195,9 +817,60 @@ impl Meta {
     /// If you are an end user of Cosmic Time, you do not want this.
     /// You want the `resume` function on [`Timeline`].
     pub fn resume(&mut self, now: Instant) {
-        if let Pause::Paused(start) = self.pause {
-            self.pause = Pause::Resumed(now - start);
+        if let Pause::Paused(time) = self.pause {
+            self.pause = Pause::NoPause;
+            self.anchor = (now, time);
+            self.update_end(now);
+        }
+    }
+
+    /// Changes the playback rate, without the animation jumping.
+    /// If you are an end user of Cosmic Time, you do not want this.
+    /// You want the `set_speed` function on [`Timeline`].
+    pub fn set_rate(&mut self, now: Instant, rate: f32) {
+        if self.pause.is_playing() {
+            self.anchor = (now, self.start + Duration::from_secs_f64(self.elapsed(now)));
+        }
+        self.rate = rate;
+        self.update_end(now);
+    }
+
+    /// Jumps to `time` into the animation.
+    /// If you are an end user of Cosmic Time, you do not want this.
+    /// You want the `seek` function on [`Timeline`].
+    pub fn seek(&mut self, now: Instant, time: Duration) {
+        let time = self.start + time;
+        if self.pause.is_playing() {
+            self.anchor = (now, time);
+        } else {
+            self.pause = Pause::Paused(time);
         }
+        self.update_end(now);
+    }
+
+    /// Jumps to a fraction (0.0 -> 1.0) of the animation.
+    /// If you are an end user of Cosmic Time, you do not want this.
+    /// You want the `seek_fraction` function on [`Timeline`].
+    pub fn seek_fraction(&mut self, now: Instant, fraction: f32) {
+        let total = self.total_length().unwrap_or(self.length.as_secs_f64());
+        let time = total * f64::from(fraction.clamp(0., 1.));
+        self.seek(now, Duration::from_secs_f64(time));
+    }
+
+    // Recalculate the real time that the animation ends at.
+    fn update_end(&mut self, now: Instant) {
+        let Some(total) = self.total_length() else {
+            return;
+        };
+        let elapsed = self.elapsed(now);
+        let remaining = if self.rate > 0. {
+            (total - elapsed) / f64::from(self.rate)
+        } else if self.rate < 0. {
+            elapsed / f64::from(-self.rate)
+        } else {
+            0.
+        };
+        self.end = now + Duration::from_secs_f64(remaining.max(0.));
     }
 }
 
@@ -207,11 +880,8 @@ impl Meta {
 pub enum Pause {
     /// Currently paused, with the relative instant into the animation it was paused at.
     Paused(Instant),
-    /// Has never been paused
+    /// Not paused. Either never paused, or has been resumed.
     NoPause,
-    /// The animation was paused, but no longer. The duration is required for the
-    /// offset of the animation.
-    Resumed(Duration),
 }
 
 impl Pause {
@@ -236,13 +906,29 @@ pub struct SubFrame {
     pub ease: Ease,
     /// The Instant of this. Converted from duration in [`Frame`]
     pub at: Instant,
+    /// If set, the spring used to move into this, instead of the ease.
+    pub spring: Option<Spring>,
+    /// The velocity the value was moving at when this was reached.
+    /// Blended into the next link of the chain.
+    /// Non-zero if a lazy [`Frame`] interrupted a moving animation.
+    pub velocity: f32,
+    /// Is this from a [`Frame::Step`]? The value switches to this halfway
+    /// through the link, instead of being blended.
+    pub step: bool,
 }
 
 impl SubFrame {
     /// Creates a new `SubFrame`.
     #[must_use]
     pub fn new(at: Instant, value: f32, ease: Ease) -> Self {
-        SubFrame { value, ease, at }
+        SubFrame {
+            value,
+            ease,
+            at,
+            spring: None,
+            velocity: 0.,
+            step: false,
+        }
     }
 }
 
@@ -269,6 +955,67 @@ impl PartialOrd for SubFrame {
     }
 }
 
+/// A typed key for one property of a keyframe, like `Container::WIDTH`.
+/// Pass it to [`Timeline::get`] instead of the property's index.
+/// Declared along with the keyframe's frames by [`crate::properties!`].
+pub struct Property<K> {
+    index: usize,
+    keyframe: PhantomData<fn() -> K>,
+}
+
+impl<K> Property<K> {
+    /// Create the key for the property at `index` in the keyframe's frames.
+    /// You probably want to use the [`crate::properties!`] macro instead.
+    #[must_use]
+    pub const fn new(index: usize) -> Self {
+        Property {
+            index,
+            keyframe: PhantomData,
+        }
+    }
+}
+
+impl<K> Clone for Property<K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K> Copy for Property<K> {}
+
+impl<K> PartialEq for Property<K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index
+    }
+}
+
+impl<K> Eq for Property<K> {}
+
+impl<K> std::fmt::Debug for Property<K> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Property").field(&self.index).finish()
+    }
+}
+
+/// Anything [`Timeline::get`] can look up a property with.
+/// Either a typed [`Property`], or the raw index of the property.
+pub trait PropertyKey: Copy {
+    /// The index of the property in the keyframe's frames.
+    fn index(self) -> usize;
+}
+
+impl<K> PropertyKey for Property<K> {
+    fn index(self) -> usize {
+        self.index
+    }
+}
+
+impl PropertyKey for usize {
+    fn index(self) -> usize {
+        self
+    }
+}
+
 /// Returned from [`Timeline::get`]
 /// Has all the data needed for simple animtions,
 /// and for style-like animations where some data
@@ -283,6 +1030,26 @@ pub struct Interped {
     pub value: f32,
     /// The percent done of this link in the chain.
     pub percent: f32,
+    /// The instantaneous velocity of the value, per second.
+    pub velocity: f32,
+}
+
+impl Interped {
+    // Steps switch from the previous value to the next halfway through the link.
+    fn stepped(self, step: bool) -> Self {
+        if !step {
+            return self;
+        }
+        Interped {
+            value: if self.percent < 0.5 {
+                self.previous
+            } else {
+                self.next
+            },
+            velocity: 0.,
+            ..self
+        }
+    }
 }
 
 impl Timeline {
@@ -294,6 +1061,10 @@ impl Timeline {
             tracks: HashMap::new(),
             pendings: HashMap::new(),
             now: None,
+            speed: 1.0,
+            color_space: ColorSpace::default(),
+            events: Vec::new(),
+            groups: HashMap::new(),
         }
     }
 
@@ -311,21 +1082,43 @@ impl Timeline {
     }
 
     /// Need to pause an animation? Use this! Pass the same widget Id
-    /// used to create the chain.
+    /// used to create the chain, or the Id of a [`crate::Group`].
     pub fn pause(&mut self, id: impl Into<widget::Id>) -> &mut Self {
         let id = id.into();
+        if let Some(group) = self.group(&id) {
+            for chain in group.chains {
+                self.pause_member(chain, Pause::Paused(Instant::now()), Pending::Pause);
+            }
+            return self;
+        }
         let _ = self.pendings.insert(id, Pending::Pause);
         self
     }
 
     /// Need to resume an animation? Use this! Pass the same widget Id
-    /// used to pause the chain.
+    /// used to pause the chain, or the Id of a [`crate::Group`].
     pub fn resume(&mut self, id: impl Into<widget::Id>) -> &mut Self {
         let id = id.into();
+        if let Some(group) = self.group(&id) {
+            for chain in group.chains {
+                self.pause_member(chain, Pause::NoPause, Pending::Resume);
+            }
+            return self;
+        }
         let _ = self.pendings.insert(id, Pending::Resume);
         self
     }
 
+    // Pause or resume a chain of a group. Chains that have not
+    // started yet start paused, or playing.
+    fn pause_member(&mut self, id: widget::Id, pause: Pause, pending: Pending) {
+        if let Some(Pending::Chain(chain)) = self.pendings.get_mut(&id) {
+            chain.pause = pause;
+        } else {
+            let _ = self.pendings.insert(id, pending);
+        }
+    }
+
     /// Hammer Time? Pause all animations with this.
     pub fn pause_all(&mut self) -> &mut Self {
         let _ = self
@@ -342,45 +1135,257 @@ impl Timeline {
         self
     }
 
+    /// Change the playback speed of an animation. Pass the same widget Id
+    /// used to create the chain, or the Id of a [`crate::Group`].
+    /// 1.0 is normal speed, 0.5 is half speed, and 2.0 is double speed.
+    /// Negative values play the animation in reverse.
+    /// Takes effect immediately, continuing from the animation's current value.
+    pub fn set_speed(&mut self, id: impl Into<widget::Id>, speed: f32) -> &mut Self {
+        let id = id.into();
+        if let Some(group) = self.group(&id) {
+            for chain in group.chains {
+                let _ = self.set_speed(chain, speed);
+            }
+            return self;
+        }
+        let now = self.get_now();
+        let global_speed = self.speed;
+        if let Some(Pending::Chain(pending)) = self.pendings.get_mut(&id) {
+            pending.speed = speed;
+        } else if let Some((meta, _track)) = self.tracks.get_mut(&id) {
+            meta.speed = speed;
+            meta.set_rate(now, speed * global_speed);
+        }
+        self
+    }
+
+    /// Change the playback speed of all animations. Great for slow motion
+    /// debugging, or an accessability setting.
+    /// Multiplied with each animation's own speed set by `set_speed`.
+    /// Takes effect immediately, continuing from each animation's current value.
+    pub fn set_global_speed(&mut self, speed: f32) -> &mut Self {
+        let now = self.get_now();
+        self.speed = speed;
+        for (meta, _track) in self.tracks.values_mut() {
+            meta.set_rate(now, meta.speed * speed);
+        }
+        self
+    }
+
+    /// The global playback speed set by `set_global_speed`.
+    #[must_use]
+    pub fn global_speed(&self) -> f32 {
+        self.speed
+    }
+
+    /// Change the color space that animated colors are blended in.
+    /// Keyframes that set their own color space use that instead.
+    pub fn set_color_space(&mut self, color_space: ColorSpace) -> &mut Self {
+        self.color_space = color_space;
+        self
+    }
+
+    /// The color space set by `set_color_space`.
+    #[must_use]
+    pub fn color_space(&self) -> ColorSpace {
+        self.color_space
+    }
+
+    /// Jump an animation to `time` into the animation. Pass the same widget Id
+    /// used to create the chain, or the Id of a [`crate::Group`].
+    /// Works for both playing and paused animations. Playing animations continue
+    /// playing from the new time, paused animations stay paused there.
+    /// Great for driving an animation from a slider, or a gesture.
+    pub fn seek(&mut self, id: impl Into<widget::Id>, time: Duration) -> &mut Self {
+        self.seek_with(id.into(), Seek::Time(time))
+    }
+
+    /// Like `seek`, but jumps to a fraction (0.0 -> 1.0) of the animation.
+    /// For animations that repeat a set number of times, this is a fraction of all
+    /// repeats. For animations that repeat forever, a fraction of one loop.
+    pub fn seek_fraction(&mut self, id: impl Into<widget::Id>, fraction: f32) -> &mut Self {
+        self.seek_with(id.into(), Seek::Fraction(fraction))
+    }
+
+    fn seek_with(&mut self, id: widget::Id, seek: Seek) -> &mut Self {
+        if let Some(group) = self.group(&id) {
+            match group.span {
+                // The chains of a group share its clock, so they are all seeked to the
+                // same time. Chains that have not started by then hold their first frame.
+                Some((start, end)) => {
+                    let time = match seek {
+                        Seek::Time(time) => time,
+                        Seek::Fraction(fraction) => (end - start).mul_f32(fraction.clamp(0., 1.)),
+                    };
+                    for chain in group.chains {
+                        let _ = self.seek_with(chain, Seek::Time(start + time));
+                    }
+                }
+                None => {
+                    if let Some(state) = self.groups.get_mut(&group.root) {
+                        state.seek = Some((id, seek));
+                    }
+                }
+            }
+            return self;
+        }
+        let now = self.get_now();
+        if let Some(Pending::Chain(pending)) = self.pendings.get_mut(&id) {
+            pending.seek = Some(seek);
+        } else if let Some((meta, _track)) = self.tracks.get_mut(&id) {
+            match seek {
+                Seek::Time(time) => meta.seek(now, time),
+                Seek::Fraction(fraction) => meta.seek_fraction(now, fraction),
+            }
+        }
+        self
+    }
+
     /// Add an animation chain to the timeline!
     /// Each animation Id is unique. It is imposible to use the same Id
     /// for two animations.
-    pub fn set_chain(&mut self, chain: impl Into<Chain>) -> &mut Self {
+    /// Groups of chains, like [`Stagger`], are all added at once.
+    pub fn set_chain(&mut self, chain: impl IntoChains) -> &mut Self {
         self.set_chain_with_options(chain, Pause::NoPause)
     }
 
     /// Like `set_chain` but the animation will start paused on it's first frame.
-    pub fn set_chain_paused(&mut self, chain: impl Into<Chain>) -> &mut Self {
+    pub fn set_chain_paused(&mut self, chain: impl IntoChains) -> &mut Self {
         self.set_chain_with_options(chain, Pause::Paused(Instant::now()))
     }
 
-    fn set_chain_with_options(&mut self, chain: impl Into<Chain>, pause: Pause) -> &mut Self {
+    fn set_chain_with_options(&mut self, chain: impl IntoChains, pause: Pause) -> &mut Self {
         // TODO should be removed. Used iterators for pre-release
         // cosmic-time implementation. Keyframes should just pass a Vec<Vec<Frame>>
-        let chain = chain.into();
-        let id = chain.id;
-        let repeat = chain.repeat;
-
-        let _ = self
-            .pendings
-            .insert(id, Pending::Chain(repeat, chain.links, pause));
+        for chain in chain.into_chains() {
+            self.leave_groups(&chain.id, chain.group.as_ref());
+            if let Some((root, layout)) = chain
+                .group
+                .as_ref()
+                .and_then(|layout| Some((layout.id.clone()?, layout)))
+            {
+                if !self
+                    .groups
+                    .get(&root)
+                    .is_some_and(|state| Arc::ptr_eq(&state.layout, layout))
+                {
+                    let _ = self.groups.insert(
+                        root,
+                        GroupState {
+                            layout: layout.clone(),
+                            schedule: None,
+                            seek: None,
+                            left: HashSet::new(),
+                        },
+                    );
+                }
+            }
+            let _ = self.pendings.insert(
+                chain.id,
+                Pending::Chain(PendingChain {
+                    repeat: chain.repeat,
+                    links: chain.links,
+                    values: chain.values,
+                    pause,
+                    speed: 1.0,
+                    seek: None,
+                }),
+            );
+        }
         self
     }
 
     /// Remove's any animation. Usually not necessary, unless you may have
     /// a very large animation that needs to be "garage collected" when done.
+    /// Clearing a [`crate::Group`] clears all of its chains.
     pub fn clear_chain(&mut self, id: impl Into<widget::Id>) -> &mut Self {
         let id = id.into();
-        let _ = self.tracks.remove(&id);
+        if let Some(group) = self.group(&id) {
+            for chain in group.chains {
+                let _ = self.clear_chain(chain);
+            }
+            if group.root == id {
+                let _ = self.groups.remove(&id);
+            }
+            return self;
+        }
+        self.leave_groups(&id, None);
+        let now = self.get_now();
+        if let Some((meta, _track)) = self.tracks.remove(&id) {
+            if !meta.is_finished(now) {
+                self.events.push(AnimationEvent::Interrupted(id));
+            }
+        }
         self
     }
 
+    // A chain is replaced or cleared, so it leaves every group but the one
+    // with `layout`. Groups without any chains left are removed.
+    fn leave_groups(&mut self, chain: &widget::Id, layout: Option<&Arc<Layout>>) {
+        self.groups.retain(|_root, state| {
+            if layout.is_some_and(|layout| Arc::ptr_eq(layout, &state.layout)) {
+                let _ = state.left.remove(chain);
+                return true;
+            }
+            if state.layout.chains().contains(chain) {
+                let _ = state.left.insert(chain.clone());
+            }
+            !state.chains(&state.layout).is_empty()
+        });
+    }
+
     /// Use this in your `update()`.
     /// Updates the timeline's time so that animations can continue atomically.
+    /// Emits [`AnimationEvent`]s for any animations that looped or completed.
     pub fn now(&mut self, now: Instant) {
+        self.emit_progress(now);
         self.now = Some(now);
     }
 
+    // Emits `Looped` and `Completed` events for progress made since the last `now`.
+    fn emit_progress(&mut self, now: Instant) {
+        if let Some(previous) = self.now.filter(|previous| *previous < now) {
+            let mut completed = Vec::new();
+            for (id, (meta, _track)) in &self.tracks {
+                let (loops, is_completed) = meta.progressed(previous, now);
+                for _ in 0..loops {
+                    self.events.push(AnimationEvent::Looped(id.clone()));
+                }
+                if is_completed {
+                    self.events.push(AnimationEvent::Completed(id.clone()));
+                    completed.push(id);
+                }
+            }
+
+            // Groups complete along with the last of their chains.
+            for state in self
+                .groups
+                .values()
+                .filter(|state| state.schedule.is_some())
+            {
+                for (id, group) in state.layout.groups() {
+                    let chains = state.chains(group);
+                    if chains.iter().any(|chain| completed.contains(&chain))
+                        && chains
+                            .iter()
+                            .filter_map(|chain| self.tracks.get(chain))
+                            .all(|(meta, _track)| meta.is_finished(now))
+                    {
+                        self.events.push(AnimationEvent::Completed(id.clone()));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Take all [`AnimationEvent`]s that have happened since the last call.
+    /// Call this in your `update()` after `now`, to react to animations
+    /// starting, looping, completing, or being interrupted.
+    /// Events are kept until drained.
+    pub fn drain_events(&mut self) -> Vec<AnimationEvent> {
+        std::mem::take(&mut self.events)
+    }
+
     /// Starts all pending animations.
     pub fn start(&mut self) {
         self.start_at(Instant::now());
@@ -388,11 +1393,20 @@ impl Timeline {
 
     /// Starts all pending animations at some other time that isn't now.
     pub fn start_at(&mut self, now: Instant) {
+        // Animations may complete before being replaced.
+        self.emit_progress(now);
         let mut pendings = std::mem::take(&mut self.pendings);
         for (id, pending) in pendings.drain() {
             match pending {
-                Pending::Chain(repeat, chain, pause) => {
-                    let mut end = now;
+                Pending::Chain(PendingChain {
+                    repeat,
+                    links: chain,
+                    values,
+                    pause,
+                    speed,
+                    seek,
+                }) => {
+                    let rate = speed * self.speed;
                     // The time that the chain was `set_chain_paused` is not
                     // necessaritly the same as the atomic pause time used here.
                     // Fix that here.
@@ -402,57 +1416,95 @@ impl Timeline {
                         pause
                     };
 
+                    // Lazy keyframes of a typed track continue from the value it is at.
+                    let values = values.map(|values| {
+                        let previous = self
+                            .tracks
+                            .get(&id)
+                            .and_then(|(meta, _track)| meta.values.as_deref())
+                            .zip(self.get(&id, 0));
+                        values.resolve(previous)
+                    });
+
+                    // Each property (column) is scheduled on its own. A property missing
+                    // from a keyframe is timed like the first property present there.
                     let cols = chain[0].len();
-                    let rows = chain.len();
-                    let mut peekable = chain.into_iter().peekable();
-                    let mut specific_chain = Vec::with_capacity(rows);
-                    while let Some(current) = peekable.next() {
-                        let time = end;
-                        if let Some(next) = peekable.peek() {
-                            let mut counter = 0;
-                            if let Some((c_frame, n_frame)) =
-                                current.iter().zip(next.iter()).find(|(c_frame, n_frame)| {
-                                    counter += 1;
-                                    c_frame.is_some() && n_frame.is_some()
+                    // The last frame in each column, and the velocity carried into it.
+                    let mut previous: Vec<Option<(Frame, f32)>> = vec![None; cols];
+                    let mut times = vec![now; cols];
+                    let mut transposed: Vec<Vec<SubFrame>> = vec![Vec::new(); cols];
+                    for current in chain {
+                        let current: Vec<Option<(Frame, f32)>> = current
+                            .into_iter()
+                            .enumerate()
+                            .map(|(i, maybe_frame)| {
+                                maybe_frame.map(|mut frame| {
+                                    let velocity = frame.carried_velocity(self, &id, i);
+                                    frame.to_eager(self, &id, i);
+                                    (frame, velocity)
                                 })
-                            {
-                                let mut c = c_frame.expect("Previous check guarentees saftey");
-                                let mut n = n_frame.expect("Previous check guarentees saftey");
-                                c.to_eager(self, &id, counter - 1);
-                                n.to_eager(self, &id, counter - 1);
-                                let duration = n.get_duration(&c);
-                                end += duration;
-                            }
-                        }
+                            })
+                            .collect();
 
-                        let specific_row = current.into_iter().enumerate().fold(
-                            Vec::with_capacity(cols),
-                            |mut acc, (i, maybe_frame)| {
-                                if let Some(mut frame) = maybe_frame {
-                                    frame.to_eager(self, &id, i);
-                                    acc.push(Some(frame.to_subframe(time)))
-                                } else {
-                                    acc.push(None)
+                        // How long it takes a frame to animate into from the previous
+                        // frame in its column.
+                        let durations: Vec<Option<Duration>> = current
+                            .iter()
+                            .zip(&previous)
+                            .map(|(maybe, previous)| {
+                                let (frame, _velocity) = maybe.as_ref()?;
+                                let (p_frame, velocity) = previous.as_ref()?;
+                                // Velocity is stored relative to the animation's own clock.
+                                let velocity = if rate == 0. { 0. } else { velocity / rate };
+                                Some(match (&values, frame.movement_type()) {
+                                    // Typed tracks move from value to value, not by index.
+                                    (Some(values), MovementType::Speed(speed)) => {
+                                        let from = p_frame.get_value() as usize;
+                                        let to = frame.get_value() as usize;
+                                        speed.calc_duration(0., values.distance(from, to))
+                                    }
+                                    _ => frame.get_duration_with_velocity(p_frame, velocity),
+                                })
+                            })
+                            .collect();
+                        let fallback = durations.iter().flatten().next().copied();
+
+                        for (j, (maybe_frame, duration)) in
+                            current.into_iter().zip(durations).enumerate()
+                        {
+                            times[j] += duration.or(fallback).unwrap_or_default();
+                            if let Some((frame, velocity)) = maybe_frame {
+                                previous[j] = Some((frame, velocity));
+                                let mut subframe = frame.to_subframe(times[j]);
+                                // Velocity is stored relative to the animation's own clock.
+                                if rate != 0. {
+                                    subframe.velocity = velocity / rate;
                                 }
-                                acc
-                            },
-                        );
-                        specific_chain.push(specific_row);
+                                transposed[j].push(subframe);
+                            }
+                        }
                     }
-                    let transposed = specific_chain.into_iter().fold(
-                        vec![Vec::new(); cols],
-                        |mut acc: Vec<Vec<SubFrame>>, row| {
-                            row.into_iter().enumerate().for_each(|(j, maybe_item)| {
-                                if let Some(item) = maybe_item {
-                                    acc[j].push(item)
-                                }
-                            });
-                            acc
-                        },
-                    );
+                    // The chain ends once every property has finished.
+                    let end = transposed
+                        .iter()
+                        .filter_map(|column| column.last().map(|subframe| subframe.at))
+                        .max()
+                        .unwrap_or(now);
 
-                    let meta = Meta::new(repeat, now, end, end - now, pause);
-                    let _ = self.tracks.insert(id, (meta, transposed));
+                    let mut meta = Meta::new(repeat, now, end, end - now, pause);
+                    meta.speed = speed;
+                    meta.values = values;
+                    meta.set_rate(now, rate);
+                    match seek {
+                        Some(Seek::Time(time)) => meta.seek(now, time),
+                        Some(Seek::Fraction(fraction)) => meta.seek_fraction(now, fraction),
+                        None => {}
+                    }
+                    let previous = self.tracks.insert(id.clone(), (meta, transposed));
+                    if previous.is_some_and(|(meta, _track)| !meta.is_finished(now)) {
+                        self.events.push(AnimationEvent::Interrupted(id.clone()));
+                    }
+                    self.events.push(AnimationEvent::Started(id));
                 }
                 Pending::Pause => {
                     if let Some((meta, _track)) = self.tracks.get_mut(&id) {
@@ -476,15 +1528,100 @@ impl Timeline {
                 }
             }
         }
-        self.now(now);
+        self.schedule_groups(now);
+        self.now = Some(now);
+    }
+
+    // Now that the chains of new groups have started, and their lengths are known,
+    // hold the start of each chain until it is its turn to play.
+    fn schedule_groups(&mut self, now: Instant) {
+        let pending: Vec<widget::Id> = self
+            .groups
+            .iter()
+            .filter(|(_id, state)| state.schedule.is_none())
+            .map(|(id, _state)| id.clone())
+            .collect();
+        for root in pending {
+            let Some(state) = self.groups.get(&root) else {
+                continue;
+            };
+            let layout = state.layout.clone();
+            let left = state.left.clone();
+            let length = |id: &widget::Id| {
+                self.tracks
+                    .get(id)
+                    .map_or(Duration::ZERO, |(meta, _track)| {
+                        meta.total_length()
+                            .map_or(meta.length, Duration::from_secs_f64)
+                    })
+            };
+            let mut schedule = Schedule::default();
+            let _ = layout.schedule(Duration::ZERO, &length, &mut schedule);
+
+            for (id, offset) in &schedule.chains {
+                if left.contains(id) {
+                    continue;
+                }
+                if let Some((meta, track)) = self.tracks.get_mut(id) {
+                    delay_track(meta, track, *offset, now);
+                }
+            }
+            for (id, _start, _end) in &schedule.groups {
+                self.events.push(AnimationEvent::Started(id.clone()));
+            }
+            let seek = self.groups.get_mut(&root).and_then(|state| {
+                state.schedule = Some(schedule);
+                state.seek.take()
+            });
+            if let Some((id, seek)) = seek {
+                let _ = self.seek_with(id, seek);
+            }
+        }
+    }
+
+    // The members of the group with this Id, if it is one.
+    fn group(&self, id: &widget::Id) -> Option<GroupMembers> {
+        self.groups.iter().find_map(|(root, state)| {
+            let (_id, layout) = state
+                .layout
+                .groups()
+                .into_iter()
+                .find(|(group, _layout)| *group == id)?;
+            let span = state.schedule.as_ref().and_then(|schedule| {
+                schedule
+                    .groups
+                    .iter()
+                    .find(|(group, _start, _end)| group == id)
+                    .map(|(_group, start, end)| (*start, *end))
+            });
+            Some(GroupMembers {
+                root: root.clone(),
+                chains: state.chains(layout),
+                span,
+            })
+        })
     }
 
     /// Get the [`Interped`] value for an animation.
     /// Use internaly by Cosmic Time.
-    /// index is the index that the keyframe arbitratily assigns to each
-    /// widget modifier (think width/height).
+    /// `property` is the keyframe's typed key for a widget modifier (think
+    /// `Container::WIDTH`), or the raw index the keyframe assigns to it.
     #[must_use]
-    pub fn get(&self, id: &widget::Id, index: usize) -> Option<Interped> {
+    pub fn get(&self, id: &widget::Id, property: impl PropertyKey) -> Option<Interped> {
+        self.interp(id, property.index())
+            .map(|(interped, _is_spring)| interped)
+    }
+
+    /// Get the value of a typed [`crate::Track`], with the same Id.
+    /// Returns `None` if the track is not on the timeline, or animates another type.
+    #[must_use]
+    pub fn value<T: Animatable>(&self, id: &widget::Id) -> Option<T> {
+        let (meta, _track) = self.tracks.get(id)?;
+        crate::track::value(meta.values.as_deref()?, self.get(id, 0)?)
+    }
+
+    // Like `get`, but also returns if the value is currently driven by a spring.
+    fn interp(&self, id: &widget::Id, index: usize) -> Option<(Interped, bool)> {
         let now = self.get_now();
         // Get requested modifier_timeline or skip
         let (meta, mut modifier_timeline) = if let Some((meta, chain)) = self.tracks.get(id) {
@@ -497,11 +1634,7 @@ impl Timeline {
             return None;
         };
 
-        let relative_now = match meta.pause {
-            Pause::NoPause => relative_time(&now, meta),
-            Pause::Resumed(delay) => relative_time(&now.checked_sub(delay).unwrap(), meta),
-            Pause::Paused(time) => relative_time(&time, meta),
-        };
+        let relative_now = meta.time(now);
 
         // Loop through modifier_timeline, returning the interpolated value if possible.
         let mut accumulator: Option<&SubFrame> = None;
@@ -513,44 +1646,221 @@ impl Timeline {
                 (None, None) => return None,
                 // Accumulator found in previous loop, but no greater value. Means animation duration has expired.
                 (Some(acc), None) => {
-                    return Some(Interped {
-                        previous: acc.value,
-                        next: acc.value,
-                        percent: 1.0,
-                        value: acc.value,
-                    });
+                    return Some((
+                        Interped {
+                            previous: acc.value,
+                            next: acc.value,
+                            percent: 1.0,
+                            value: acc.value,
+                            velocity: 0.,
+                        },
+                        false,
+                    ));
                 }
                 // Found accumulator in middle-ish of timeline
                 (Some(acc), Some(modifier)) => {
                     // Can not interpolate between this one and next value?
-                    if relative_now >= modifier.at || acc.value == modifier.value {
+                    if relative_now >= modifier.at {
                         accumulator = Some(modifier);
+                    // Held between two equal values. `previous` and `next` are of this
+                    // link, so values blended from several properties, like colors,
+                    // read every property at the same link.
+                    } else if acc.value == modifier.value && acc.velocity == 0. {
+                        return Some((
+                            Interped {
+                                previous: acc.value,
+                                next: acc.value,
+                                percent: 1.0,
+                                value: acc.value,
+                                velocity: 0.,
+                            },
+                            false,
+                        ));
+                    // Spring between these two, thus calculate and return that value.
+                    } else if let Some(spring) = modifier.spring {
+                        let elapsed = relative_now.duration_since(acc.at).as_secs_f32();
+                        let (offset, velocity) =
+                            spring.solve(acc.value - modifier.value, acc.velocity, elapsed);
+
+                        let previous = acc.value;
+                        let next = modifier.value;
+                        let value = next + offset;
+                        let percent = if previous == next {
+                            1.0
+                        } else {
+                            (value - previous) / (next - previous)
+                        };
+
+                        return Some((
+                            Interped {
+                                previous,
+                                next,
+                                value,
+                                percent,
+                                velocity: velocity * meta.rate,
+                            }
+                            .stepped(modifier.step),
+                            true,
+                        ));
                     // Can interpolate between these two, thus calculate and return that value.
                     } else {
                         let elapsed = relative_now.duration_since(acc.at).as_millis() as f32;
                         let duration = (modifier.at - acc.at).as_millis() as f32;
+                        let p = elapsed / duration;
 
                         let previous = acc.value;
                         let next = modifier.value;
-                        let percent = modifier.ease.tween(elapsed / duration);
-                        let value = lerp(
-                            acc.value,
-                            modifier.value,
-                            modifier.ease.tween(elapsed / duration),
-                        );
-
-                        return Some(Interped {
-                            previous,
-                            next,
-                            value,
-                            percent,
-                        });
+                        let percent = modifier.ease.tween(p);
+                        // Velocity is per second, durations are in millis.
+                        let mut velocity =
+                            (next - previous) * modifier.ease.derivative(p) * 1000. / duration;
+                        let mut value = lerp(acc.value, modifier.value, percent);
+
+                        // Blend in the velocity of the interrupted animation,
+                        // decaying to nothing by the end of this link.
+                        // h(p) = p(1 - p)^2, where h'(0) = 1 and h(1) = h'(1) = 0.
+                        if acc.velocity != 0. {
+                            value += acc.velocity * (duration / 1000.) * p * (1. - p).powi(2);
+                            velocity += acc.velocity * (1. - p) * (1. - 3. * p);
+                        }
+
+                        return Some((
+                            Interped {
+                                previous,
+                                next,
+                                value,
+                                percent,
+                                velocity: velocity * meta.rate,
+                            }
+                            .stepped(modifier.step),
+                            false,
+                        ));
                     }
                 }
             }
         }
     }
 
+    /// Is the animation currently playing? Pass the same widget Id
+    /// used to create the chain.
+    /// Animations that are paused, finished, or not on the timeline are not running.
+    /// Animations that have been `set_chain`, but not yet started, are.
+    #[must_use]
+    pub fn is_running(&self, id: &widget::Id) -> bool {
+        if let Some(group) = self.group(id) {
+            return group.chains.iter().any(|chain| self.is_running(chain));
+        }
+        if let Some(Pending::Chain(pending)) = self.pendings.get(id) {
+            return pending.pause.is_playing();
+        }
+        let now = self.get_now();
+        self.tracks.get(id).is_some_and(|(meta, _track)| {
+            meta.pause.is_playing() && meta.rate != 0. && !meta.is_finished(now)
+        })
+    }
+
+    /// Is the animation paused? Pass the same widget Id used to create the chain.
+    #[must_use]
+    pub fn is_paused(&self, id: &widget::Id) -> bool {
+        if let Some(group) = self.group(id) {
+            return !group.chains.is_empty()
+                && group.chains.iter().all(|chain| self.is_paused(chain));
+        }
+        if let Some(Pending::Chain(pending)) = self.pendings.get(id) {
+            return !pending.pause.is_playing();
+        }
+        self.tracks
+            .get(id)
+            .is_some_and(|(meta, _track)| !meta.pause.is_playing())
+    }
+
+    /// How far (0.0 -> 1.0) an animation has played.
+    /// For animations that repeat a set number of times, this is a fraction of all
+    /// repeats. For animations that repeat forever, a fraction of the current loop.
+    /// Animations that have not started yet are at 0.0.
+    /// Returns `None` if the animation is not on the timeline.
+    #[must_use]
+    pub fn progress(&self, id: &widget::Id) -> Option<f32> {
+        if let Some(group) = self.group(id) {
+            let Some((start, end)) = group.span else {
+                return Some(0.);
+            };
+            // The chains of a group share its clock.
+            let (meta, _track) = group
+                .chains
+                .iter()
+                .find_map(|chain| self.tracks.get(chain))?;
+            let elapsed = meta.raw_elapsed(self.get_now()) - start.as_secs_f64();
+            let length = (end - start).as_secs_f64();
+            return Some(if length > 0. {
+                (elapsed / length).clamp(0., 1.) as f32
+            } else {
+                1.
+            });
+        }
+        if let Some(Pending::Chain(pending)) = self.pendings.get(id) {
+            return Some(match pending.seek {
+                Some(Seek::Fraction(fraction)) => fraction.clamp(0., 1.),
+                _ => 0.,
+            });
+        }
+        let now = self.get_now();
+        self.tracks.get(id).map(|(meta, _track)| meta.progress(now))
+    }
+
+    /// How long until an animation completes, at its current speed.
+    /// Paused animations report the time left once resumed.
+    /// Returns `None` if the animation is not on the timeline, has not started yet,
+    /// repeats forever, or has a speed of 0.
+    #[must_use]
+    pub fn remaining(&self, id: &widget::Id) -> Option<Duration> {
+        if let Some(group) = self.group(id) {
+            // Not scheduled yet.
+            let _span = group.span?;
+            return group
+                .chains
+                .iter()
+                .map(|chain| self.remaining(chain))
+                .try_fold(Duration::ZERO, |max, remaining| Some(max.max(remaining?)));
+        }
+        if let Some(Pending::Chain(_)) = self.pendings.get(id) {
+            return None;
+        }
+        let now = self.get_now();
+        self.tracks
+            .get(id)
+            .and_then(|(meta, _track)| meta.remaining(now))
+    }
+
+    /// How many times an animation has looped. Each bounce of a
+    /// ping pong animation counts as a loop.
+    /// Returns `None` if the animation is not on the timeline.
+    #[must_use]
+    pub fn loop_count(&self, id: &widget::Id) -> Option<u32> {
+        if let Some(Pending::Chain(_)) = self.pendings.get(id) {
+            return Some(0);
+        }
+        let now = self.get_now();
+        self.tracks
+            .get(id)
+            .map(|(meta, _track)| meta.loop_count(now))
+    }
+
+    /// Iterate over the Ids of all running animations. See `is_running`.
+    pub fn running(&self) -> impl Iterator<Item = &widget::Id> + '_ {
+        let pending = self
+            .pendings
+            .iter()
+            .filter_map(|(id, pending)| match pending {
+                Pending::Chain(pending) if pending.pause.is_playing() => Some(id),
+                _ => None,
+            });
+        let tracks = self.tracks.keys().filter(|id| {
+            !matches!(self.pendings.get(*id), Some(Pending::Chain(_))) && self.is_running(id)
+        });
+        pending.chain(tracks)
+    }
+
     /// Check if the timeline is idle
     /// The timeline is considered idle if all animations meet
     /// one of the final criteria:
@@ -562,7 +1872,7 @@ impl Timeline {
         let now = self.now;
         !(now.is_some()
             && self.tracks.values().any(|track| {
-                (track.0.repeat == Repeat::Forever && track.0.pause.is_playing())
+                (track.0.repeat.is_infinite() && track.0.pause.is_playing() && track.0.rate != 0.)
                     || (track.0.end >= now.unwrap() && track.0.pause.is_playing())
             }))
     }
@@ -592,18 +1902,559 @@ impl Timeline {
     }
 }
 
-// Used for animations that loop.
-// Given the current `Instant`, it returns the relative instant in the animation that
-// corresponds with the first loop of the animation.
-fn relative_time(now: &Instant, meta: &Meta) -> Instant {
-    if meta.repeat == Repeat::Never {
-        *now
+// `a - b` in seconds, that may be negative.
+fn signed_secs(a: Instant, b: Instant) -> f64 {
+    if a >= b {
+        (a - b).as_secs_f64()
     } else {
-        let repeat_num = (*now - meta.start).as_millis() / meta.length.as_millis();
-        let reduce_by = repeat_num * meta.length.as_millis();
-        now.checked_sub(Duration::from_millis(
-            reduce_by.clamp(0, u64::MAX.into()).try_into().unwrap(),
-        ))
-        .expect("Your animatiion has been runnning for 5.84x10^6 centuries.")
+        -(b - a).as_secs_f64()
+    }
+}
+
+// Hold the first frame of a started animation for `offset`, so it starts later
+// in its group. Unlike `Chain::delay`, the hold is only played once, before any loop.
+fn delay_track(meta: &mut Meta, track: &mut [Vec<SubFrame>], offset: Duration, now: Instant) {
+    if offset.is_zero() {
+        return;
+    }
+    for column in track.iter_mut() {
+        // Whatever the value was doing before the group started is long gone.
+        if let Some(first) = column.first_mut() {
+            first.velocity = 0.;
+        }
+    }
+    meta.delay = offset;
+    meta.update_end(now);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Speed;
+
+    fn chain(id: &widget::Id, frames: Vec<Frame>) -> Chain {
+        Chain::new(
+            id.clone(),
+            Repeat::Never,
+            frames
+                .into_iter()
+                .map(|f| vec![Some(f)])
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    #[test]
+    fn velocity() {
+        let id = widget::Id::unique();
+        let start = Instant::now();
+        let mut timeline = Timeline::new();
+        let _ = timeline.set_chain(chain(
+            &id,
+            vec![
+                Frame::eager(Duration::ZERO, 0., Linear::InOut.into()),
+                Frame::eager(Duration::from_secs(1), 100., Linear::InOut.into()),
+            ],
+        ));
+        timeline.start_at(start);
+        timeline.now(start + Duration::from_millis(500));
+
+        let interped = timeline.get(&id, 0).unwrap();
+        assert_eq!(50., interped.value.round());
+        assert_eq!(100., interped.velocity.round());
+    }
+
+    #[test]
+    fn lazy_velocity() {
+        let id = widget::Id::unique();
+        let start = Instant::now();
+        let mut timeline = Timeline::new();
+        let _ = timeline.set_chain(chain(
+            &id,
+            vec![
+                Frame::eager(Duration::ZERO, 0., Linear::InOut.into()),
+                Frame::eager(Duration::from_secs(1), 100., Linear::InOut.into()),
+            ],
+        ));
+        timeline.start_at(start);
+
+        // Interrupt halfway, heading back to 0.
+        let interrupt = start + Duration::from_millis(500);
+        for (frame, velocity) in [
+            (Frame::lazy(Duration::ZERO, 0., Linear::InOut.into()), -50.),
+            (
+                Frame::lazy_velocity(Duration::ZERO, 0., Linear::InOut.into()),
+                50.,
+            ),
+        ] {
+            let mut timeline = timeline.clone();
+            timeline.now(interrupt);
+            let _ = timeline.set_chain(chain(
+                &id,
+                vec![
+                    frame,
+                    Frame::eager(Duration::from_secs(1), 0., Linear::InOut.into()),
+                ],
+            ));
+            timeline.start_at(interrupt);
+            assert_eq!(velocity, timeline.get(&id, 0).unwrap().velocity.round());
+        }
+    }
+
+    fn linear(id: &widget::Id) -> Chain {
+        chain(
+            id,
+            vec![
+                Frame::eager(Duration::ZERO, 0., Linear::InOut.into()),
+                Frame::eager(Duration::from_secs(1), 100., Linear::InOut.into()),
+            ],
+        )
+    }
+
+    #[test]
+    fn speed() {
+        let id = widget::Id::unique();
+        let start = Instant::now();
+        let mut timeline = Timeline::new();
+        let _ = timeline.set_chain(linear(&id));
+        timeline.start_at(start);
+
+        timeline.now(start + Duration::from_millis(200));
+        let _ = timeline.set_speed(id.clone(), 2.0);
+        assert_eq!(20., timeline.get(&id, 0).unwrap().value.round());
+        assert_eq!(200., timeline.get(&id, 0).unwrap().velocity.round());
+
+        timeline.now(start + Duration::from_millis(400));
+        assert_eq!(60., timeline.get(&id, 0).unwrap().value.round());
+
+        let _ = timeline.set_global_speed(-0.5);
+        timeline.now(start + Duration::from_millis(600));
+        assert_eq!(40., timeline.get(&id, 0).unwrap().value.round());
+
+        // Reversed to the start, then idle.
+        timeline.now(start + Duration::from_millis(1000));
+        assert_eq!(0., timeline.get(&id, 0).unwrap().value.round());
+        timeline.now(start + Duration::from_millis(1100));
+        assert!(timeline.is_idle());
+    }
+
+    #[test]
+    fn pause_resume() {
+        let id = widget::Id::unique();
+        let start = Instant::now();
+        let mut timeline = Timeline::new();
+        let _ = timeline.set_chain(linear(&id));
+        timeline.start_at(start);
+
+        let _ = timeline.pause(id.clone());
+        timeline.start_at(start + Duration::from_millis(300));
+        timeline.now(start + Duration::from_millis(800));
+        assert_eq!(30., timeline.get(&id, 0).unwrap().value.round());
+
+        let _ = timeline.resume(id.clone());
+        timeline.start_at(start + Duration::from_millis(800));
+        timeline.now(start + Duration::from_millis(1000));
+        assert_eq!(50., timeline.get(&id, 0).unwrap().value.round());
+        // The animation was paused for 500ms, so is still running.
+        timeline.now(start + Duration::from_millis(1400));
+        assert!(!timeline.is_idle());
+    }
+
+    #[test]
+    fn repeat() {
+        let id = widget::Id::unique();
+        let start = Instant::now();
+        let at = |millis| start + Duration::from_millis(millis);
+
+        let mut timeline = Timeline::new();
+        let mut times = linear(&id);
+        times.repeat = Repeat::Times(2);
+        let _ = timeline.set_chain(times);
+        timeline.start_at(start);
+        timeline.now(at(1250));
+        assert_eq!(25., timeline.get(&id, 0).unwrap().value.round());
+        timeline.now(at(2500));
+        assert_eq!(100., timeline.get(&id, 0).unwrap().value.round());
+        assert!(timeline.is_idle());
+
+        let mut ping_pong = linear(&id);
+        ping_pong.repeat = Repeat::PingPong;
+        let _ = timeline.set_chain(ping_pong);
+        timeline.start_at(start);
+        timeline.now(at(1250));
+        assert_eq!(75., timeline.get(&id, 0).unwrap().value.round());
+        timeline.now(at(2250));
+        assert_eq!(25., timeline.get(&id, 0).unwrap().value.round());
+        assert!(!timeline.is_idle());
+    }
+
+    #[test]
+    fn reverse() {
+        let id = widget::Id::unique();
+        let start = Instant::now();
+        let mut timeline = Timeline::new();
+        let _ = timeline.set_chain(
+            chain(
+                &id,
+                vec![
+                    Frame::eager(Duration::ZERO, 0., Linear::InOut.into()),
+                    Frame::eager(Duration::from_secs(1), 100., Linear::InOut.into()),
+                    Frame::eager(Duration::from_secs(3), 200., Linear::InOut.into()),
+                ],
+            )
+            .reverse(),
+        );
+        timeline.start_at(start);
+
+        timeline.now(start + Duration::from_millis(1500));
+        assert_eq!(150., timeline.get(&id, 0).unwrap().value.round());
+        timeline.now(start + Duration::from_millis(3500));
+        assert_eq!(50., timeline.get(&id, 0).unwrap().value.round());
+    }
+
+    #[test]
+    fn seek() {
+        let id = widget::Id::unique();
+        let start = Instant::now();
+        let at = |millis| start + Duration::from_millis(millis);
+        let mut timeline = Timeline::new();
+        let _ = timeline.set_chain(linear(&id));
+        timeline.start_at(start);
+
+        // Interrupt with a lazy chain, that is then scrubbed.
+        timeline.now(at(500));
+        let _ = timeline.set_chain_paused(chain(
+            &id,
+            vec![
+                Frame::lazy(Duration::ZERO, 0., Linear::InOut.into()),
+                Frame::eager(Duration::from_secs(1), 0., Linear::InOut.into()),
+            ],
+        ));
+        timeline.start_at(at(500));
+
+        let _ = timeline.seek_fraction(id.clone(), 0.5);
+        assert_eq!(25., timeline.get(&id, 0).unwrap().value.round());
+        let _ = timeline.seek(id.clone(), Duration::ZERO);
+        assert_eq!(50., timeline.get(&id, 0).unwrap().value.round());
+
+        // Seeking a playing animation keeps it playing.
+        let _ = timeline.resume(id.clone());
+        timeline.start_at(at(600));
+        let _ = timeline.seek(id.clone(), Duration::from_millis(200));
+        timeline.now(at(800));
+        assert_eq!(30., timeline.get(&id, 0).unwrap().value.round());
+    }
+
+    #[test]
+    fn events() {
+        let id = widget::Id::unique();
+        let start = Instant::now();
+        let at = |millis| start + Duration::from_millis(millis);
+        let mut timeline = Timeline::new();
+
+        let mut times = linear(&id);
+        times.repeat = Repeat::Times(3);
+        let _ = timeline.set_chain(times);
+        timeline.start_at(start);
+        assert_eq!(
+            vec![AnimationEvent::Started(id.clone())],
+            timeline.drain_events()
+        );
+
+        timeline.now(at(2500));
+        assert_eq!(
+            vec![
+                AnimationEvent::Looped(id.clone()),
+                AnimationEvent::Looped(id.clone())
+            ],
+            timeline.drain_events()
+        );
+        timeline.now(at(3000));
+        assert_eq!(
+            vec![AnimationEvent::Completed(id.clone())],
+            timeline.drain_events()
+        );
+        timeline.now(at(3500));
+        assert!(timeline.drain_events().is_empty());
+
+        let _ = timeline.set_chain(linear(&id));
+        timeline.start_at(at(3500));
+        let _ = timeline.set_chain(linear(&id));
+        timeline.start_at(at(4000));
+        assert_eq!(
+            vec![
+                AnimationEvent::Started(id.clone()),
+                AnimationEvent::Interrupted(id.clone()),
+                AnimationEvent::Started(id.clone())
+            ],
+            timeline.drain_events()
+        );
+    }
+
+    #[test]
+    fn query() {
+        let id = widget::Id::unique();
+        let other = widget::Id::unique();
+        let start = Instant::now();
+        let at = |millis| start + Duration::from_millis(millis);
+        let mut timeline = Timeline::new();
+
+        assert!(!timeline.is_running(&id));
+        assert_eq!(None, timeline.progress(&id));
+        assert_eq!(None, timeline.loop_count(&id));
+
+        let mut times = linear(&id);
+        times.repeat = Repeat::Times(3);
+        let _ = timeline.set_chain(times).set_chain_paused(linear(&other));
+        assert!(timeline.is_running(&id));
+        assert!(timeline.is_paused(&other));
+        assert_eq!(vec![&id], timeline.running().collect::<Vec<_>>());
+        assert_eq!(Some(0.), timeline.progress(&id));
+        assert_eq!(None, timeline.remaining(&id));
+
+        timeline.start_at(start);
+        timeline.now(at(1500));
+        assert!(timeline.is_running(&id));
+        assert!(!timeline.is_running(&other));
+        assert_eq!(Some(0.5), timeline.progress(&id));
+        assert_eq!(Some(Duration::from_millis(1500)), timeline.remaining(&id));
+        assert_eq!(Some(1), timeline.loop_count(&id));
+        assert_eq!(Some(0.), timeline.progress(&other));
+
+        let _ = timeline.set_speed(id.clone(), 0.5);
+        assert_eq!(Some(Duration::from_millis(3000)), timeline.remaining(&id));
+
+        timeline.now(at(5000));
+        assert!(!timeline.is_running(&id));
+        assert_eq!(Some(1.), timeline.progress(&id));
+        assert_eq!(Some(Duration::ZERO), timeline.remaining(&id));
+        assert_eq!(Some(2), timeline.loop_count(&id));
+        assert_eq!(0, timeline.running().count());
+
+        let mut forever = linear(&id);
+        forever.repeat = Repeat::Forever;
+        let _ = timeline.set_chain(forever);
+        timeline.start_at(at(5000));
+        timeline.now(at(7250));
+        assert!(timeline.is_running(&id));
+        assert_eq!(Some(0.25), timeline.progress(&id));
+        assert_eq!(None, timeline.remaining(&id));
+        assert_eq!(Some(2), timeline.loop_count(&id));
+    }
+
+    #[test]
+    fn stagger() {
+        let ids: Vec<_> = (0..3).map(|_| widget::Id::unique()).collect();
+        let start = Instant::now();
+        let at = |millis| start + Duration::from_millis(millis);
+        let mut timeline = Timeline::new();
+        let value = |timeline: &Timeline, id| timeline.get(id, 0).map(|i| i.value);
+
+        let template = linear(&widget::Id::unique());
+        let _ = timeline.set_chain(
+            Stagger::new(ids.clone(), template.clone()).delay(Duration::from_millis(500)),
+        );
+        timeline.start_at(start);
+        timeline.now(at(750));
+        assert_eq!(Some(75.), value(&timeline, &ids[0]));
+        assert_eq!(Some(25.), value(&timeline, &ids[1]));
+        assert_eq!(Some(0.), value(&timeline, &ids[2]));
+        timeline.now(at(2000));
+        assert_eq!(Some(100.), value(&timeline, &ids[1]));
+        assert_eq!(Some(100.), value(&timeline, &ids[2]));
+
+        let curve =
+            Stagger::new(ids.clone(), template).delay_curve(Duration::from_secs(1), Linear::InOut);
+        let _ = timeline.set_chain(curve);
+        timeline.start_at(at(2000));
+        timeline.now(at(2750));
+        assert_eq!(Some(75.), value(&timeline, &ids[0]));
+        assert_eq!(Some(25.), value(&timeline, &ids[1]));
+        assert_eq!(Some(0.), value(&timeline, &ids[2]));
+    }
+
+    #[test]
+    fn group() {
+        let [a, b, c, group, inner] = [(); 5].map(|_| widget::Id::unique());
+        let start = Instant::now();
+        let at = |millis| start + Duration::from_millis(millis);
+        let mut timeline = Timeline::new();
+        let value = |timeline: &Timeline, id| timeline.get(id, 0).map(|i| i.value);
+        let values =
+            |timeline: &Timeline| [&a, &b, &c].map(|id| value(timeline, id).map(f32::round));
+
+        // `a` plays from 0 to 1s, `b` from 0.5s to 1.5s, and `c` from 1s to 2s.
+        let sequence = crate::Group::sequence(group.clone())
+            .add(linear(&a))
+            .label("middle")
+            .add_before(Duration::from_millis(500), linear(&b))
+            .add_at("middle", crate::parallel![inner.clone(), linear(&c)]);
+        let _ = timeline.set_chain(sequence);
+        timeline.start_at(start);
+        let events = timeline.drain_events();
+        assert!(events.contains(&AnimationEvent::Started(group.clone())));
+        assert!(events.contains(&AnimationEvent::Started(inner.clone())));
+
+        timeline.now(at(250));
+        assert_eq!([Some(25.), Some(0.), Some(0.)], values(&timeline));
+        timeline.now(at(1250));
+        assert_eq!([Some(100.), Some(75.), Some(25.)], values(&timeline));
+        assert_eq!(Some(0.625), timeline.progress(&group));
+        assert_eq!(Some(Duration::from_millis(750)), timeline.remaining(&group));
+
+        let _ = timeline.pause(group.clone());
+        timeline.start_at(at(1250));
+        assert!(timeline.is_paused(&group));
+        let _ = timeline.seek(group.clone(), Duration::from_millis(500));
+        timeline.now(at(1500));
+        assert_eq!([Some(50.), Some(0.), Some(0.)], values(&timeline));
+        assert_eq!(Some(0.25), timeline.progress(&group));
+
+        let _ = timeline.resume(group.clone());
+        timeline.start_at(at(1500));
+        timeline.now(at(1750));
+        assert_eq!([Some(75.), Some(25.), Some(0.)], values(&timeline));
+        assert!(timeline.is_running(&inner));
+        let _ = timeline.drain_events();
+
+        timeline.now(at(3250));
+        let events = timeline.drain_events();
+        assert!(events.contains(&AnimationEvent::Completed(inner.clone())));
+        assert!(events.contains(&AnimationEvent::Completed(group.clone())));
+        assert!(!timeline.is_running(&group));
+        assert_eq!(Some(1.), timeline.progress(&group));
+
+        // A seek before the group starts is applied once it is scheduled.
+        let stagger =
+            Stagger::new(vec![a.clone(), b.clone()], linear(&c)).delay(Duration::from_millis(500));
+        let _ = timeline
+            .set_chain(crate::sequence![group.clone(), linear(&c), stagger])
+            .seek(group.clone(), Duration::from_millis(1750));
+        timeline.start_at(at(3250));
+        assert_eq!([Some(75.), Some(25.), Some(100.)], values(&timeline));
+    }
+
+    #[test]
+    fn group_repeat() {
+        let [a, b, group, other] = [(); 4].map(|_| widget::Id::unique());
+        let start = Instant::now();
+        let at = |millis| start + Duration::from_millis(millis);
+        let mut timeline = Timeline::new();
+        let value = |timeline: &Timeline, id| timeline.get(id, 0).map(|i| i.value.round());
+
+        // `b` waits for `a` once, then plays twice, from 1s to 3s.
+        let mut repeated = linear(&b);
+        repeated.repeat = Repeat::Times(2);
+        let _ = timeline.set_chain(crate::sequence![group.clone(), linear(&a), repeated]);
+        timeline.start_at(start);
+        let _ = timeline.drain_events();
+
+        timeline.now(at(500));
+        assert_eq!(Some(0.), value(&timeline, &b));
+        timeline.now(at(1500));
+        assert_eq!(Some(50.), value(&timeline, &b));
+        timeline.now(at(2500));
+        assert_eq!(Some(50.), value(&timeline, &b));
+        assert_eq!(Some(1), timeline.loop_count(&b));
+        assert_eq!(Some(Duration::from_millis(500)), timeline.remaining(&group));
+        assert!((timeline.progress(&group).unwrap() - 2.5 / 3.).abs() < 1e-6);
+        let events = timeline.drain_events();
+        assert_eq!(
+            1,
+            events
+                .iter()
+                .filter(|e| **e == AnimationEvent::Looped(b.clone()))
+                .count()
+        );
+        assert!(!events.contains(&AnimationEvent::Completed(group.clone())));
+
+        timeline.now(at(3000));
+        assert!(timeline
+            .drain_events()
+            .contains(&AnimationEvent::Completed(group.clone())));
+        assert_eq!(Some(1.), timeline.progress(&group));
+        assert_eq!(Some(Duration::ZERO), timeline.remaining(&group));
+        assert_eq!(Some(100.), value(&timeline, &b));
+
+        // `b` moves to another group, so it is no longer paused with its old one.
+        let _ = timeline.set_chain(crate::sequence![other.clone(), linear(&b)]);
+        timeline.start_at(at(3000));
+        let _ = timeline.pause(group.clone());
+        timeline.start_at(at(3000));
+        timeline.now(at(3500));
+        assert!(timeline.is_paused(&a));
+        assert!(!timeline.is_paused(&b));
+        assert_eq!(Some(50.), value(&timeline, &b));
+
+        // With all of its chains gone, the old group is gone too.
+        let _ = timeline.clear_chain(a.clone());
+        assert_eq!(None, timeline.progress(&group));
+        assert_eq!(Some(0.5), timeline.progress(&other));
+    }
+
+    #[test]
+    fn per_property_timing() {
+        let id = widget::Id::unique();
+        let start = Instant::now();
+        let at = |millis| start + Duration::from_millis(millis);
+        let mut timeline = Timeline::new();
+        let eager = |millis, value| {
+            Some(Frame::eager(
+                Duration::from_millis(millis),
+                value,
+                Linear::InOut.into(),
+            ))
+        };
+
+        let links = vec![
+            vec![eager(0, 0.), eager(0, 0.), eager(0, 0.)],
+            vec![eager(1000, 100.), eager(500, 100.), eager(2000, 100.)],
+            vec![eager(1000, 0.), None, eager(1000, 0.)],
+        ];
+        let _ = timeline.set_chain(Chain::new(id.clone(), Repeat::Never, links));
+        timeline.start_at(start);
+        let value = |timeline: &Timeline, index| timeline.get(&id, index).map(|i| i.value);
+
+        timeline.now(at(500));
+        assert_eq!(Some(50.), value(&timeline, 0));
+        assert_eq!(Some(100.), value(&timeline, 1));
+        assert_eq!(Some(25.), value(&timeline, 2));
+        // Each property moves on to the next keyframe as soon as it is done.
+        timeline.now(at(1500));
+        assert_eq!(Some(50.), value(&timeline, 0));
+        assert_eq!(Some(100.), value(&timeline, 1));
+        assert_eq!(Some(75.), value(&timeline, 2));
+        timeline.now(at(2500));
+        assert_eq!(Some(0.), value(&timeline, 0));
+        assert_eq!(Some(100.), value(&timeline, 1));
+        assert_eq!(Some(50.), value(&timeline, 2));
+        assert_eq!(Some(Duration::from_millis(500)), timeline.remaining(&id));
+    }
+
+    #[test]
+    fn per_property_speed() {
+        let id = widget::Id::unique();
+        let start = Instant::now();
+        let at = |millis| start + Duration::from_millis(millis);
+        let mut timeline = Timeline::new();
+        let speed = |value| {
+            Some(Frame::eager(
+                Speed::per_secs(100.),
+                value,
+                Linear::InOut.into(),
+            ))
+        };
+
+        let links = vec![vec![speed(0.), speed(0.)], vec![speed(100.), speed(200.)]];
+        let _ = timeline.set_chain(Chain::new(id.clone(), Repeat::Never, links));
+        timeline.start_at(start);
+        let value = |timeline: &Timeline, index| timeline.get(&id, index).map(|i| i.value);
+
+        timeline.now(at(1000));
+        assert_eq!(Some(100.), value(&timeline, 0));
+        assert_eq!(Some(100.), value(&timeline, 1));
+        assert_eq!(Some(Duration::from_secs(1)), timeline.remaining(&id));
+        timeline.now(at(2000));
+        assert_eq!(Some(100.), value(&timeline, 0));
+        assert_eq!(Some(200.), value(&timeline, 1));
     }
 }
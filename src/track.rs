@@ -0,0 +1,217 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use crate::keyframes::Repeat;
+use crate::reexports::iced_core::widget;
+use crate::timeline::{Chain, Frame, Interped, TrackValues};
+use crate::{Animatable, Duration, Ease, Linear, MovementType};
+
+/// A keyframe of a typed [`Track`].
+#[must_use = "Keyframes are intended to be used in an animation chain."]
+#[derive(Debug, Clone)]
+pub struct Key<T> {
+    at: MovementType,
+    ease: Ease,
+    // `None` for lazy keyframes.
+    value: Option<T>,
+}
+
+impl<T: Animatable> Key<T> {
+    /// Animate to `value`.
+    pub fn new(at: impl Into<MovementType>, value: T) -> Self {
+        Key {
+            at: at.into(),
+            ease: Linear::InOut.into(),
+            value: Some(value),
+        }
+    }
+
+    /// Continue from the value the track is at when the animation starts.
+    /// If nothing was animating, this is the first value in the track.
+    pub fn lazy(at: impl Into<MovementType>) -> Self {
+        Key {
+            at: at.into(),
+            ease: Linear::InOut.into(),
+            value: None,
+        }
+    }
+
+    /// The ease into this keyframe.
+    pub fn ease(mut self, ease: impl Into<Ease>) -> Self {
+        self.ease = ease.into();
+        self
+    }
+}
+
+/// An animation of a single [`Animatable`] value, like a [`crate::reexports::iced_core::Point`]
+/// or your own type. For custom widgets that don't want to split their values
+/// into `f32`s and manage index tables.
+///
+/// Read the value back with [`crate::Timeline::value`], using the same Id.
+/// ```ignore
+/// let id = widget::Id::new("ball");
+/// let track = Track::new(id.clone())
+///     .link(Key::new(Duration::ZERO, Point::ORIGIN))
+///     .link(Key::new(Duration::from_millis(500), Point::new(100., 50.)));
+/// self.timeline.set_chain(track).start();
+/// // In `view()`
+/// let position: Point = self.timeline.value(&id).unwrap_or(Point::ORIGIN);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Track<T> {
+    id: widget::Id,
+    keys: Vec<Key<T>>,
+    // How long to hold, after how many keys.
+    holds: Vec<(usize, Duration)>,
+    delay: Duration,
+    repeat: Repeat,
+    reverse: bool,
+}
+
+impl<T: Animatable> Track<T> {
+    /// Create a new, empty track.
+    #[must_use]
+    pub fn new(id: impl Into<widget::Id>) -> Self {
+        Track {
+            id: id.into(),
+            keys: Vec::new(),
+            holds: Vec::new(),
+            delay: Duration::ZERO,
+            repeat: Repeat::Never,
+            reverse: false,
+        }
+    }
+
+    crate::chain_builder!(keys: Key<T>);
+}
+
+impl<T: Animatable> From<Track<T>> for Chain {
+    fn from(track: Track<T>) -> Self {
+        // The timeline animates the index of the value each keyframe is at.
+        let frames: Vec<Frame> = track
+            .keys
+            .iter()
+            .enumerate()
+            .map(|(i, key)| Frame::eager(key.at, i as f32, key.ease))
+            .collect();
+        let keys = Keys(track.keys.into_iter().map(|key| key.value).collect());
+        Chain::with_values(track.id, track.repeat, Vec::new(), Arc::new(keys)).build(
+            frames.into_iter().map(|frame| vec![Some(frame)]),
+            track.holds,
+            track.delay,
+            track.reverse,
+        )
+    }
+}
+
+// The values of a track that has not started yet. Lazy keyframes are `None`.
+#[derive(Debug)]
+struct Keys<T>(Vec<Option<T>>);
+
+// The values of a started track.
+#[derive(Debug)]
+struct Values<T>(Vec<T>);
+
+impl<T: Animatable> TrackValues for Keys<T> {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn resolve(&self, previous: Option<(&dyn TrackValues, Interped)>) -> Arc<dyn TrackValues> {
+        let current = previous
+            .and_then(|(values, interped)| value::<T>(values, interped))
+            .or_else(|| self.0.iter().flatten().next().cloned());
+        let values = match current {
+            Some(current) => self
+                .0
+                .iter()
+                .map(|value| value.clone().unwrap_or_else(|| current.clone()))
+                .collect(),
+            // Only lazy keyframes, and nothing to continue from.
+            None => Vec::new(),
+        };
+        Arc::new(Values(values))
+    }
+
+    fn distance(&self, from: usize, to: usize) -> f32 {
+        match (self.0.get(from), self.0.get(to)) {
+            (Some(Some(from)), Some(Some(to))) => from.distance(to),
+            _ => 0.,
+        }
+    }
+}
+
+impl<T: Animatable> TrackValues for Values<T> {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn resolve(&self, _previous: Option<(&dyn TrackValues, Interped)>) -> Arc<dyn TrackValues> {
+        Arc::new(Values(self.0.clone()))
+    }
+
+    fn distance(&self, from: usize, to: usize) -> f32 {
+        match (self.0.get(from), self.0.get(to)) {
+            (Some(from), Some(to)) => from.distance(to),
+            _ => 0.,
+        }
+    }
+}
+
+// The value a started track is at, or `None` if it holds another type.
+pub(crate) fn value<T: Animatable>(values: &dyn TrackValues, interped: Interped) -> Option<T> {
+    let Values(values) = values.as_any().downcast_ref::<Values<T>>()?;
+    let previous = values.get(interped.previous.round() as usize)?;
+    let next = values.get(interped.next.round() as usize)?;
+    let percent = if interped.previous == interped.next {
+        1.
+    } else {
+        (interped.value - interped.previous) / (interped.next - interped.previous)
+    };
+    Some(previous.lerp(next, percent))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::reexports::iced_core::{Padding, Point};
+    use crate::{Instant, Speed, Timeline};
+
+    #[test]
+    fn track() {
+        let id = widget::Id::unique();
+        let start = Instant::now();
+        let mut timeline = Timeline::new();
+        let track = Track::new(id.clone())
+            .link(Key::new(Duration::ZERO, Point::ORIGIN))
+            .link(Key::new(Duration::from_secs(1), Point::new(100., 50.)))
+            // 100 pixels away, at 100 pixels per second.
+            .link(Key::new(Speed::per_secs(100.), Point::new(100., 150.)));
+        let _ = timeline.set_chain(track);
+        timeline.start_at(start);
+
+        timeline.now(start + Duration::from_millis(500));
+        assert_eq!(Some(Point::new(50., 25.)), timeline.value(&id));
+        // Another type is not there.
+        assert!(timeline.value::<Padding>(&id).is_none());
+        timeline.now(start + Duration::from_millis(1250));
+        assert_eq!(Some(Point::new(100., 75.)), timeline.value(&id));
+        timeline.now(start + Duration::from_millis(2000));
+        assert_eq!(Some(Point::new(100., 150.)), timeline.value(&id));
+
+        // Lazy keyframes continue from the current value.
+        let track = Track::new(id.clone())
+            .link(Key::lazy(Duration::ZERO))
+            .link(Key::new(Duration::from_secs(1), Point::ORIGIN));
+        timeline.now(start + Duration::from_millis(1250));
+        let _ = timeline.set_chain(track);
+        timeline.start_at(start + Duration::from_millis(1250));
+        timeline.now(start + Duration::from_millis(1750));
+        assert_eq!(Some(Point::new(50., 37.5)), timeline.value(&id));
+
+        // Composite values blend as a whole.
+        let padding = Animatable::lerp(&Padding::ZERO, &Padding::from([4., 8.]), 0.5);
+        assert_eq!((2., 4.), (padding.top, padding.left));
+        assert_eq!(8., Padding::ZERO.distance(&Padding::from([4., 8.])));
+    }
+}
@@ -3,6 +3,7 @@
 use crate::{
     reexports::{iced_core, iced_style},
     utils::static_array_from_iter,
+    ColorSpace,
 };
 
 #[cfg(feature = "libcosmic")]
@@ -12,7 +13,13 @@ pub mod cosmic_button;
 #[cfg(feature = "libcosmic")]
 pub mod cosmic_container;
 #[cfg(feature = "libcosmic")]
+pub mod cosmic_flip;
+#[cfg(feature = "libcosmic")]
+pub mod cosmic_resize;
+#[cfg(feature = "libcosmic")]
 pub mod cosmic_toggler;
+#[cfg(feature = "libcosmic")]
+pub mod cosmic_transform;
 
 #[cfg(feature = "libcosmic")]
 pub use cards::Cards;
@@ -21,21 +28,43 @@ pub use cosmic_button::Button;
 #[cfg(feature = "libcosmic")]
 pub use cosmic_container::Container;
 #[cfg(feature = "libcosmic")]
+pub use cosmic_flip::Flip;
+#[cfg(feature = "libcosmic")]
+pub use cosmic_resize::Resize;
+#[cfg(feature = "libcosmic")]
 pub use cosmic_toggler::Toggler;
+#[cfg(feature = "libcosmic")]
+pub use cosmic_transform::Transform;
 
 #[cfg(not(feature = "libcosmic"))]
 pub mod button;
 #[cfg(not(feature = "libcosmic"))]
 pub mod container;
 #[cfg(not(feature = "libcosmic"))]
+pub mod flip;
+#[cfg(not(feature = "libcosmic"))]
+pub mod resize;
+#[cfg(not(feature = "libcosmic"))]
 pub mod toggler;
+#[cfg(not(feature = "libcosmic"))]
+pub mod transform;
 
 #[cfg(not(feature = "libcosmic"))]
 pub use button::Button;
 #[cfg(not(feature = "libcosmic"))]
 pub use container::Container;
 #[cfg(not(feature = "libcosmic"))]
+pub use flip::Flip;
+#[cfg(not(feature = "libcosmic"))]
+pub use resize::Resize;
+#[cfg(not(feature = "libcosmic"))]
 pub use toggler::Toggler;
+#[cfg(not(feature = "libcosmic"))]
+pub use transform::Transform;
+
+pub mod flex;
+
+pub use flex::{Column, Row};
 
 /// A convenience type to optimize style-able widgets,
 /// to only do the "expensize" style calculations if needed.
@@ -47,102 +76,200 @@ pub enum StyleType<T> {
     Blend(T, T, f32),
 }
 
+/// Appearance values set directly by keyframes, instead of picked from a stylesheet.
+/// Every value that is set replaces the stylesheet's value when the widget is drawn.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct AppearanceOverride {
+    /// The background, a solid color or a gradient.
+    pub background: Option<Background>,
+    /// The text color.
+    pub text_color: Option<Color>,
+    /// The border color.
+    pub border_color: Option<Color>,
+    /// The border width.
+    pub border_width: Option<f32>,
+    /// The radius of every corner of the border.
+    pub border_radius: Option<f32>,
+}
+
+impl AppearanceOverride {
+    /// Are no values set?
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Replace the values that are set in a container's appearance.
+    #[must_use]
+    pub fn container(
+        &self,
+        mut appearance: iced_style::container::Appearance,
+    ) -> iced_style::container::Appearance {
+        if let Some(background) = self.background {
+            appearance.background = Some(background);
+        }
+        if let Some(text_color) = self.text_color {
+            appearance.text_color = Some(text_color);
+        }
+        if let Some(border_color) = self.border_color {
+            appearance.border.color = border_color;
+        }
+        if let Some(border_width) = self.border_width {
+            appearance.border.width = border_width;
+        }
+        if let Some(border_radius) = self.border_radius {
+            appearance.border.radius = border_radius.into();
+        }
+        appearance
+    }
+
+    /// Replace the values that are set in a button's appearance.
+    #[must_use]
+    pub fn button(
+        &self,
+        mut appearance: iced_style::button::Appearance,
+    ) -> iced_style::button::Appearance {
+        if let Some(background) = self.background {
+            appearance.background = Some(background);
+        }
+        if let Some(text_color) = self.text_color {
+            appearance.text_color = text_color;
+        }
+        if let Some(border_color) = self.border_color {
+            appearance.border.color = border_color;
+        }
+        if let Some(border_width) = self.border_width {
+            appearance.border.width = border_width;
+        }
+        if let Some(border_radius) = self.border_radius {
+            appearance.border.radius = border_radius.into();
+        }
+        appearance
+    }
+}
+
+/// A convenience type for animated lengths. Lengths that are not `Fixed`
+/// can only be blended once the widget knows how much space it has,
+/// so blends are resolved at layout time. See [`Resize`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LengthType {
+    /// The length is not being animated, or both ends of the animation are `Fixed`.
+    Static(Length),
+    /// The length is being animated. Blend between the two lengths.
+    Blend(Length, Length, f32),
+}
+
+impl LengthType {
+    /// The length reported to the parent widget, so that the parent
+    /// gives this widget the space it will need.
+    #[must_use]
+    pub fn length(&self) -> Length {
+        match *self {
+            LengthType::Static(length) => length,
+            LengthType::Blend(one, two, _percent) => {
+                if two.fill_factor() != 0 {
+                    two
+                } else if one.fill_factor() != 0 {
+                    one
+                } else {
+                    Length::Shrink
+                }
+            }
+        }
+    }
+
+    /// Does resolving this length need the size of the content?
+    #[must_use]
+    pub fn is_shrink(&self) -> bool {
+        match *self {
+            LengthType::Static(length) => length == Length::Shrink,
+            LengthType::Blend(one, two, _percent) => one == Length::Shrink || two == Length::Shrink,
+        }
+    }
+
+    /// Resolve to a size in pixels. `available` is the space given to the
+    /// length reported by `length`, `shrink` is the size of the content.
+    /// Filling lengths are scaled by their portion of the reported length.
+    #[must_use]
+    pub fn resolve(&self, available: f32, shrink: f32) -> f32 {
+        let factor = f32::from(self.length().fill_factor().max(1));
+        let resolve = |length: Length| match length {
+            Length::Fixed(pixels) => pixels,
+            Length::Shrink => shrink,
+            _ if !available.is_finite() => shrink,
+            fill => available * f32::from(fill.fill_factor()) / factor,
+        };
+        match *self {
+            LengthType::Static(length) => resolve(length),
+            LengthType::Blend(one, two, percent) => {
+                crate::lerp(resolve(one), resolve(two), percent)
+            }
+        }
+    }
+}
+
+/// Resolve a widget's animated lengths to lengths its layout understands.
+/// Blends are resolved against the space in `limits`. `measure` lays the
+/// widget out with the given lengths, and is only called if a blend needs
+/// the size of the content.
+pub fn resolve_lengths(
+    width: LengthType,
+    height: LengthType,
+    limits: &iced_core::layout::Limits,
+    measure: impl FnOnce(Length, Length) -> iced_core::Size,
+) -> (Length, Length) {
+    if let (LengthType::Static(width), LengthType::Static(height)) = (width, height) {
+        return (width, height);
+    }
+
+    let max = limits.max();
+    let shrink = if width.is_shrink() || height.is_shrink() {
+        measure(Length::Shrink, Length::Shrink)
+    } else {
+        iced_core::Size::ZERO
+    };
+    let resolve = |length: LengthType, available: f32, shrink: f32| match length {
+        LengthType::Static(length) => length,
+        LengthType::Blend(..) => Length::Fixed(length.resolve(available, shrink)),
+    };
+    (
+        resolve(width, max.width, shrink.width),
+        resolve(height, max.height, shrink.height),
+    )
+}
+
+impl From<Length> for LengthType {
+    fn from(length: Length) -> Self {
+        LengthType::Static(length)
+    }
+}
+
 use self::iced_core::{
     gradient::{ColorStop, Linear},
-    Background, Color, Gradient, Radians, Vector,
+    Background, Color, Gradient, Length, Radians, Shadow, Vector,
 };
 
-/// Blend between two button appearances.
+/// Blend between two container appearances.
+/// Colors are blended in `space`.
 pub fn container_blend_appearances(
     one: iced_style::container::Appearance,
     mut two: iced_style::container::Appearance,
     percent: f32,
+    space: ColorSpace,
 ) -> iced_style::container::Appearance {
     use crate::lerp;
 
-    // background
-    let background_mix: Background = match (one.background, two.background) {
-        (Some(Background::Color(c1)), Some(Background::Color(c2))) => {
-            let background_mix = static_array_from_iter::<f32, 4>(
-                c1.into_linear()
-                    .iter()
-                    .zip(c2.into_linear().iter())
-                    .map(|(o, t)| lerp(*o, *t, percent)),
-            );
-
-            Background::from(Color::from(background_mix))
-        }
-        (
-            Some(Background::Gradient(Gradient::Linear(l1))),
-            Some(Background::Gradient(Gradient::Linear(l2))),
-        ) => {
-            let angle = lerp(l1.angle.0, l2.angle.0, percent);
-            let stops = l1
-                .stops
-                .iter()
-                .zip(l2.stops.iter())
-                .map(|(o, t)| match (o, t) {
-                    (
-                        Some(ColorStop {
-                            offset: o1,
-                            color: c1,
-                        }),
-                        Some(ColorStop {
-                            color: c2,
-                            offset: o2,
-                        }),
-                    ) => {
-                        let color = static_array_from_iter::<f32, 4>(
-                            c1.into_linear()
-                                .iter()
-                                .zip(c2.into_linear().iter())
-                                .map(|(o, t)| lerp(*o, *t, percent)),
-                        );
-
-                        Some(ColorStop {
-                            color: color.into(),
-                            offset: lerp(*o1, *o2, percent),
-                        })
-                    }
-                    (a, b) => *if percent < 0.5 { a } else { b },
-                });
-
-            Background::Gradient(
-                Linear {
-                    angle: Radians(angle),
-                    stops: static_array_from_iter(stops),
-                }
-                .into(),
-            )
-        }
-        _ => Background::from(Color::from([0.0, 0.0, 0.0, 0.0])),
-    };
     // boarder color
-    let border_color = static_array_from_iter::<f32, 4>(
-        one.border
-            .color
-            .into_linear()
-            .iter()
-            .zip(two.border.color.into_linear().iter())
-            .map(|(o, t)| lerp(*o, *t, percent)),
-    );
+    let border_color = space.mix(one.border.color, two.border.color, percent);
 
     // text
     let text = one
         .text_color
-        .map(|t| {
-            static_array_from_iter::<f32, 4>(
-                t.into_linear()
-                    .iter()
-                    .zip(two.text_color.unwrap_or(t).into_linear().iter())
-                    .map(|(o, t)| lerp(*o, *t, percent)),
-            )
-        })
-        .map(Into::<Color>::into);
+        .map(|t| space.mix(t, two.text_color.unwrap_or(t), percent));
 
     let one_border_radius: [f32; 4] = one.border.radius.into();
     let two_border_radius: [f32; 4] = two.border.radius.into();
-    two.background = Some(background_mix);
+    two.background = blend_backgrounds(one.background, two.background, percent, space);
     two.border.radius = [
         lerp(one_border_radius[0], two_border_radius[0], percent),
         lerp(one_border_radius[1], two_border_radius[1], percent),
@@ -151,17 +278,20 @@ pub fn container_blend_appearances(
     ]
     .into();
     two.border.width = lerp(one.border.width, two.border.width, percent);
-    two.border.color = border_color.into();
+    two.border.color = border_color;
+    two.shadow = blend_shadows(one.shadow, two.shadow, percent, space);
     two.text_color = text;
     two
 }
 
 /// Blend between two button appearances.
+/// Colors are blended in `space`.
 #[must_use]
 pub fn button_blend_appearances(
     one: iced_style::button::Appearance,
     mut two: iced_style::button::Appearance,
     percent: f32,
+    space: ColorSpace,
 ) -> iced_style::button::Appearance {
     use crate::lerp;
 
@@ -171,76 +301,11 @@ pub fn button_blend_appearances(
     let x2 = two.shadow_offset.x;
     let y2 = two.shadow_offset.y;
 
-    // background
-    let background_mix: Background = match (one.background, two.background) {
-        (Some(Background::Color(c1)), Some(Background::Color(c2))) => {
-            Background::from(Color::from(static_array_from_iter::<f32, 4>(
-                c1.into_linear()
-                    .iter()
-                    .zip(c2.into_linear().iter())
-                    .map(|(o, t)| lerp(*o, *t, percent)),
-            )))
-        }
-        (
-            Some(Background::Gradient(Gradient::Linear(l1))),
-            Some(Background::Gradient(Gradient::Linear(l2))),
-        ) => {
-            let angle = lerp(l1.angle.0, l2.angle.0, percent);
-            let stops = l1
-                .stops
-                .iter()
-                .zip(l2.stops.iter())
-                .map(|(o, t)| match (o, t) {
-                    (
-                        Some(ColorStop {
-                            offset: o1,
-                            color: c1,
-                        }),
-                        Some(ColorStop {
-                            color: c2,
-                            offset: o2,
-                        }),
-                    ) => Some(ColorStop {
-                        color: static_array_from_iter::<f32, 4>(
-                            c1.into_linear()
-                                .iter()
-                                .zip(c2.into_linear().iter())
-                                .map(|(o, t)| lerp(*o, *t, percent)),
-                        )
-                        .into(),
-                        offset: lerp(*o1, *o2, percent),
-                    }),
-                    (a, b) => *if percent < 0.5 { a } else { b },
-                });
-            Background::Gradient(
-                Linear {
-                    angle: Radians(angle),
-                    stops: static_array_from_iter(stops),
-                }
-                .into(),
-            )
-        }
-        _ => Background::from(Color::from([0.0, 0.0, 0.0, 0.0])),
-    };
-
     // boarder color
-    let border_color: [f32; 4] = crate::utils::static_array_from_iter(
-        one.border
-            .color
-            .into_linear()
-            .iter()
-            .zip(two.border.color.into_linear().iter())
-            .map(|(o, t)| lerp(*o, *t, percent)),
-    );
+    let border_color = space.mix(one.border.color, two.border.color, percent);
 
     // text
-    let text = static_array_from_iter::<f32, 4>(
-        one.text_color
-            .into_linear()
-            .iter()
-            .zip(two.text_color.into_linear().iter())
-            .map(|(o, t)| lerp(*o, *t, percent)),
-    );
+    let text = space.mix(one.text_color, two.text_color, percent);
 
     let br1: [f32; 4] = one.border.radius.into();
     let br2: [f32; 4] = two.border.radius.into();
@@ -253,10 +318,256 @@ pub fn button_blend_appearances(
     ];
 
     two.shadow_offset = Vector::new(lerp(x1, x2, percent), lerp(y1, y2, percent));
-    two.background = Some(background_mix);
+    two.background = blend_backgrounds(one.background, two.background, percent, space);
     two.border.radius = br.into();
     two.border.width = lerp(one.border.width, two.border.width, percent);
-    two.border.color = border_color.into();
-    two.text_color = text.into();
+    two.border.color = border_color;
+    two.shadow = blend_shadows(one.shadow, two.shadow, percent, space);
+    two.text_color = text;
     two
 }
+
+/// Blend between two backgrounds. Colors are blended in `space`.
+///
+/// A solid color blends like a gradient of that one color, and a missing
+/// background like a transparent copy of the other one. Gradients with a
+/// different number of stops are resampled at the offsets of both.
+#[must_use]
+pub fn blend_backgrounds(
+    one: Option<Background>,
+    two: Option<Background>,
+    percent: f32,
+    space: ColorSpace,
+) -> Option<Background> {
+    let (one, two) = match (one, two) {
+        (None, None) => return None,
+        (Some(one), None) => (one, transparent(one)),
+        (None, Some(two)) => (transparent(two), two),
+        (Some(one), Some(two)) => (one, two),
+    };
+    let background = match (one, two) {
+        (Background::Color(c1), Background::Color(c2)) => {
+            Background::from(space.mix(c1, c2, percent))
+        }
+        (Background::Color(c1), Background::Gradient(Gradient::Linear(l2))) => {
+            blend_gradients(solid(c1, &l2), l2, percent, space)
+        }
+        (Background::Gradient(Gradient::Linear(l1)), Background::Color(c2)) => {
+            blend_gradients(l1, solid(c2, &l1), percent, space)
+        }
+        (
+            Background::Gradient(Gradient::Linear(l1)),
+            Background::Gradient(Gradient::Linear(l2)),
+        ) => blend_gradients(l1, l2, percent, space),
+    };
+    Some(background)
+}
+
+/// Blend between two shadows. Colors are blended in `space`.
+#[must_use]
+pub fn blend_shadows(one: Shadow, two: Shadow, percent: f32, space: ColorSpace) -> Shadow {
+    use crate::lerp;
+
+    // An invisible shadow has no color to blend from, only fade in the other's.
+    let fade = |color: Color, other: Color| {
+        if color.a == 0. {
+            Color { a: 0., ..other }
+        } else {
+            color
+        }
+    };
+    Shadow {
+        color: space.mix(
+            fade(one.color, two.color),
+            fade(two.color, one.color),
+            percent,
+        ),
+        offset: Vector::new(
+            lerp(one.offset.x, two.offset.x, percent),
+            lerp(one.offset.y, two.offset.y, percent),
+        ),
+        blur_radius: lerp(one.blur_radius, two.blur_radius, percent),
+    }
+}
+
+fn blend_gradients(one: Linear, two: Linear, percent: f32, space: ColorSpace) -> Background {
+    use crate::lerp;
+
+    let stops1: Vec<ColorStop> = one.stops.iter().flatten().copied().collect();
+    let stops2: Vec<ColorStop> = two.stops.iter().flatten().copied().collect();
+    let pairs: Vec<(ColorStop, ColorStop)> = if stops1.len() == stops2.len() {
+        stops1.into_iter().zip(stops2).collect()
+    } else {
+        let mut offsets: Vec<f32> = stops1.iter().chain(&stops2).map(|s| s.offset).collect();
+        offsets.sort_by(f32::total_cmp);
+        offsets.dedup();
+        if offsets.len() > MAX_STOPS {
+            offsets = (0..MAX_STOPS)
+                .map(|i| i as f32 / (MAX_STOPS - 1) as f32)
+                .collect();
+        }
+        offsets
+            .into_iter()
+            .map(|offset| {
+                let stop = |stops: &[ColorStop]| ColorStop {
+                    offset,
+                    color: sample(stops, offset),
+                };
+                (stop(&stops1), stop(&stops2))
+            })
+            .collect()
+    };
+    let stops = pairs.into_iter().map(|(s1, s2)| {
+        Some(ColorStop {
+            offset: lerp(s1.offset, s2.offset, percent),
+            color: space.mix(s1.color, s2.color, percent),
+        })
+    });
+
+    Background::Gradient(
+        Linear {
+            angle: Radians(lerp(one.angle.0, two.angle.0, percent)),
+            stops: static_array_from_iter(stops),
+        }
+        .into(),
+    )
+}
+
+// The most stops a `Linear` gradient can hold.
+const MAX_STOPS: usize = 8;
+
+// The color of the gradient at `offset`, as the renderer draws it.
+fn sample(stops: &[ColorStop], offset: f32) -> Color {
+    match stops.iter().position(|stop| stop.offset >= offset) {
+        None => stops.last().map_or(Color::TRANSPARENT, |stop| stop.color),
+        Some(0) => stops[0].color,
+        Some(i) => {
+            let (before, after) = (stops[i - 1], stops[i]);
+            let percent = (offset - before.offset) / (after.offset - before.offset);
+            ColorSpace::LinearRgb.mix(before.color, after.color, percent)
+        }
+    }
+}
+
+// A gradient shaped like `like`, that is only one color.
+fn solid(color: Color, like: &Linear) -> Linear {
+    Linear {
+        angle: like.angle,
+        stops: like
+            .stops
+            .map(|stop| stop.map(|stop| ColorStop { color, ..stop })),
+    }
+}
+
+fn transparent(background: Background) -> Background {
+    let clear = |color: Color| Color { a: 0., ..color };
+    match background {
+        Background::Color(color) => Background::Color(clear(color)),
+        Background::Gradient(Gradient::Linear(linear)) => Background::Gradient(
+            Linear {
+                angle: linear.angle,
+                stops: linear.stops.map(|stop| {
+                    stop.map(|stop| ColorStop {
+                        color: clear(stop.color),
+                        ..stop
+                    })
+                }),
+            }
+            .into(),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::reexports::iced_core::{
+        gradient::Linear, Background, Color, Gradient, Radians, Shadow, Vector,
+    };
+
+    #[test]
+    fn blend_background_and_shadow() {
+        let stops = |background: Option<Background>| match background {
+            Some(Background::Gradient(Gradient::Linear(linear))) => linear
+                .stops
+                .iter()
+                .flatten()
+                .map(|stop| (stop.offset, stop.color))
+                .collect::<Vec<_>>(),
+            other => panic!("expected a gradient, got {other:?}"),
+        };
+        let (red, blue) = (Color::from_rgb(1., 0., 0.), Color::from_rgb(0., 0., 1.));
+        let space = ColorSpace::Srgb;
+
+        // Two stops blended with three are resampled at the offsets of both.
+        let two = Linear::new(Radians(0.))
+            .add_stop(0., Color::BLACK)
+            .add_stop(1., Color::WHITE);
+        let three = Linear::new(Radians(1.))
+            .add_stop(0., red)
+            .add_stop(0.25, red)
+            .add_stop(1., blue);
+        let blend = blend_backgrounds(
+            Some(Gradient::from(two).into()),
+            Some(Gradient::from(three).into()),
+            0.,
+            space,
+        );
+        assert_eq!(
+            vec![
+                (0., Color::BLACK),
+                (
+                    0.25,
+                    ColorSpace::LinearRgb.mix(Color::BLACK, Color::WHITE, 0.25)
+                ),
+                (1., Color::WHITE)
+            ],
+            stops(blend)
+        );
+        let blend = blend_backgrounds(
+            Some(Gradient::from(two).into()),
+            Some(Gradient::from(three).into()),
+            1.,
+            space,
+        );
+        assert_eq!(vec![(0., red), (0.25, red), (1., blue)], stops(blend));
+
+        // A solid color blends into every stop of a gradient.
+        let blend = blend_backgrounds(
+            Some(Background::Color(Color::BLACK)),
+            Some(Gradient::from(three).into()),
+            0.5,
+            space,
+        );
+        let half_red = Color::from_rgb(0.5, 0., 0.);
+        assert_eq!(
+            vec![
+                (0., half_red),
+                (0.25, half_red),
+                (1., Color::from_rgb(0., 0., 0.5))
+            ],
+            stops(blend)
+        );
+
+        // No background fades in.
+        assert_eq!(
+            Some(Background::Color(Color { a: 0.5, ..red })),
+            blend_backgrounds(None, Some(Background::Color(red)), 0.5, space)
+        );
+        assert_eq!(None, blend_backgrounds(None, None, 0.5, space));
+
+        let shadow = Shadow {
+            color: red,
+            offset: Vector::new(4., 2.),
+            blur_radius: 8.,
+        };
+        assert_eq!(
+            Shadow {
+                color: Color { a: 0.5, ..red },
+                offset: Vector::new(2., 1.),
+                blur_radius: 4.,
+            },
+            blend_shadows(Shadow::default(), shadow, 0.5, space)
+        );
+    }
+}
@@ -2,7 +2,8 @@
 //!
 //! A [`Button`] has some local [`State`].
 use crate::reexports::iced::Size;
-use crate::widget::StyleType;
+use crate::widget::{resolve_lengths, AppearanceOverride, LengthType, StyleType};
+use crate::ColorSpace;
 use iced_core::event::{self, Event};
 use iced_core::layout;
 use iced_core::mouse;
@@ -30,10 +31,12 @@ where
 {
     content: Element<'a, Message, Theme, Renderer>,
     on_press: Option<Message>,
-    width: Length,
-    height: Length,
+    width: LengthType,
+    height: LengthType,
     padding: Padding,
     style: StyleType<<Theme as StyleSheet>::Style>,
+    appearance: AppearanceOverride,
+    color_space: ColorSpace,
 }
 
 impl<'a, Message, Theme, Renderer> Button<'a, Message, Theme, Renderer>
@@ -46,21 +49,23 @@ where
         Button {
             content: content.into(),
             on_press: None,
-            width: Length::Shrink,
-            height: Length::Shrink,
+            width: LengthType::Static(Length::Shrink),
+            height: LengthType::Static(Length::Shrink),
             padding: Padding::new(5.0),
             style: StyleType::Static(<Theme as StyleSheet>::Style::default()),
+            appearance: AppearanceOverride::default(),
+            color_space: ColorSpace::default(),
         }
     }
 
     /// Sets the width of the [`Button`].
-    pub fn width(mut self, width: impl Into<Length>) -> Self {
+    pub fn width(mut self, width: impl Into<LengthType>) -> Self {
         self.width = width.into();
         self
     }
 
     /// Sets the height of the [`Button`].
-    pub fn height(mut self, height: impl Into<Length>) -> Self {
+    pub fn height(mut self, height: impl Into<LengthType>) -> Self {
         self.height = height.into();
         self
     }
@@ -95,6 +100,19 @@ where
         self.style = StyleType::Blend(style1, style2, percent);
         self
     }
+
+    /// Replace values of the style's appearance with the ones that are set.
+    /// Used by keyframes to animate colors and borders without a stylesheet.
+    pub fn override_appearance(mut self, appearance: AppearanceOverride) -> Self {
+        self.appearance = appearance;
+        self
+    }
+
+    /// Sets the [`ColorSpace`] colors of the [`Button`] are blended in.
+    pub fn color_space(mut self, space: ColorSpace) -> Self {
+        self.color_space = space;
+        self
+    }
 }
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -121,7 +139,7 @@ where
     }
 
     fn size(&self) -> Size<Length> {
-        Size::new(self.width, self.height)
+        Size::new(self.width.length(), self.height.length())
     }
 
     fn layout(
@@ -130,11 +148,27 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
+        let (width, height) = resolve_lengths(self.width, self.height, limits, |width, height| {
+            layout(
+                renderer,
+                limits,
+                width,
+                height,
+                self.padding,
+                |renderer, limits| {
+                    self.content
+                        .as_widget()
+                        .layout(&mut tree.children[0], renderer, limits)
+                },
+            )
+            .size()
+        });
+
         layout(
             renderer,
             limits,
-            self.width,
-            self.height,
+            width,
+            height,
             self.padding,
             |renderer, limits| {
                 self.content
@@ -216,6 +250,8 @@ where
             self.on_press.is_some(),
             theme,
             &self.style,
+            &self.appearance,
+            self.color_space,
             || tree.state.downcast_ref::<State>(),
         );
 
@@ -349,6 +385,8 @@ pub fn draw<'a, Renderer: iced_core::renderer::Renderer, Theme: iced_style::butt
     is_enabled: bool,
     style_sheet: &dyn StyleSheet<Style = <Theme as StyleSheet>::Style>,
     style: &StyleType<<Theme as StyleSheet>::Style>,
+    appearance: &AppearanceOverride,
+    space: ColorSpace,
     state: impl FnOnce() -> &'a State,
 ) -> Appearance {
     let is_mouse_over = cursor_position.is_over(bounds);
@@ -385,9 +423,10 @@ pub fn draw<'a, Renderer: iced_core::renderer::Renderer, Theme: iced_style::butt
                 (style_sheet.active(style1), style_sheet.active(style2))
             };
 
-            button_blend_appearances(one, two, *percent)
+            button_blend_appearances(one, two, *percent, space)
         }
     };
+    let styling = appearance.button(styling);
 
     if styling.background.is_some() || styling.border.width > 0.0 {
         if styling.shadow_offset != Vector::default() {
@@ -14,7 +14,8 @@ use iced_core::{
     Shell, Size, Widget,
 };
 
-use crate::widget::StyleType;
+use crate::widget::{resolve_lengths, AppearanceOverride, LengthType, StyleType};
+use crate::ColorSpace;
 
 pub use iced_style::container::{Appearance, StyleSheet};
 
@@ -31,13 +32,15 @@ where
 {
     id: Option<Id>,
     padding: Padding,
-    width: Length,
-    height: Length,
+    width: LengthType,
+    height: LengthType,
     max_width: f32,
     max_height: f32,
     horizontal_alignment: alignment::Horizontal,
     vertical_alignment: alignment::Vertical,
     style: StyleType<<Theme as StyleSheet>::Style>,
+    appearance: AppearanceOverride,
+    color_space: ColorSpace,
     content: Element<'a, Message, Theme, Renderer>,
 }
 
@@ -54,13 +57,15 @@ where
         Container {
             id: None,
             padding: Padding::ZERO,
-            width: Length::Shrink,
-            height: Length::Shrink,
+            width: LengthType::Static(Length::Shrink),
+            height: LengthType::Static(Length::Shrink),
             max_width: f32::INFINITY,
             max_height: f32::INFINITY,
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Top,
             style: StyleType::Static(Default::default()),
+            appearance: AppearanceOverride::default(),
+            color_space: ColorSpace::default(),
             content: content.into(),
         }
     }
@@ -78,13 +83,13 @@ where
     }
 
     /// Sets the width of the [`Container`].
-    pub fn width(mut self, width: impl Into<Length>) -> Self {
+    pub fn width(mut self, width: impl Into<LengthType>) -> Self {
         self.width = width.into();
         self
     }
 
     /// Sets the height of the [`Container`].
-    pub fn height(mut self, height: impl Into<Length>) -> Self {
+    pub fn height(mut self, height: impl Into<LengthType>) -> Self {
         self.height = height.into();
         self
     }
@@ -144,6 +149,19 @@ where
         self.style = StyleType::Blend(style1, style2, percent);
         self
     }
+
+    /// Replace values of the style's appearance with the ones that are set.
+    /// Used by keyframes to animate colors and borders without a stylesheet.
+    pub fn override_appearance(mut self, appearance: AppearanceOverride) -> Self {
+        self.appearance = appearance;
+        self
+    }
+
+    /// Sets the [`ColorSpace`] colors of the [`Container`] are blended in.
+    pub fn color_space(mut self, space: ColorSpace) -> Self {
+        self.color_space = space;
+        self
+    }
 }
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -161,7 +179,7 @@ where
     }
 
     fn size(&self) -> Size<Length> {
-        Size::new(self.width, self.height)
+        Size::new(self.width.length(), self.height.length())
     }
 
     fn layout(
@@ -170,11 +188,31 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
+        let (width, height) = resolve_lengths(self.width, self.height, limits, |width, height| {
+            layout(
+                renderer,
+                limits,
+                width,
+                height,
+                self.max_width,
+                self.max_height,
+                self.padding,
+                self.horizontal_alignment,
+                self.vertical_alignment,
+                |renderer, limits| {
+                    self.content
+                        .as_widget()
+                        .layout(&mut tree.children[0], renderer, limits)
+                },
+            )
+            .size()
+        });
+
         layout(
             renderer,
             limits,
-            self.width,
-            self.height,
+            width,
+            height,
             self.max_width,
             self.max_height,
             self.padding,
@@ -261,10 +299,14 @@ where
     ) {
         let style = match &self.style {
             StyleType::Static(style) => theme.appearance(style),
-            StyleType::Blend(one, two, percent) => {
-                container_blend_appearances(theme.appearance(one), theme.appearance(two), *percent)
-            }
+            StyleType::Blend(one, two, percent) => container_blend_appearances(
+                theme.appearance(one),
+                theme.appearance(two),
+                *percent,
+                self.color_space,
+            ),
         };
+        let style = self.appearance.container(style);
 
         draw_background(renderer, &style, layout.bounds());
 
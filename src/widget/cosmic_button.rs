@@ -1,20 +1,21 @@
 //! Allow your users to perform actions by pressing a button.
 //!
 //! A [`Button`] has some local [`State`].
-use cosmic::iced_core::gradient::{ColorStop, Linear};
 use cosmic::iced_core::keyboard::key::Named;
 use cosmic::iced_runtime::core::widget::Id;
 use cosmic::iced_runtime::{keyboard, Command};
 
-use crate::utils::static_array_from_iter;
-use crate::widget::StyleType;
+use crate::widget::{
+    blend_backgrounds, resolve_lengths, AppearanceOverride, LengthType, StyleType,
+};
+use crate::ColorSpace;
 use cosmic::iced_core::event::{self, Event};
+use cosmic::iced_core::layout;
+use cosmic::iced_core::mouse;
 use cosmic::iced_core::renderer;
 use cosmic::iced_core::touch;
 use cosmic::iced_core::widget::tree::{self, Tree};
 use cosmic::iced_core::widget::Operation;
-use cosmic::iced_core::{layout, Gradient};
-use cosmic::iced_core::{mouse, Radians};
 use cosmic::iced_core::{overlay, Border};
 use cosmic::iced_core::{
     Background, Clipboard, Color, Element, Layout, Length, Padding, Point, Rectangle, Shell,
@@ -41,10 +42,12 @@ where
     label: Option<Vec<iced_accessibility::accesskit::NodeId>>,
     content: Element<'a, Message, Theme, Renderer>,
     on_press: Option<Message>,
-    width: Length,
-    height: Length,
+    width: LengthType,
+    height: LengthType,
     padding: Padding,
     style: StyleType<<Theme as StyleSheet>::Style>,
+    appearance: AppearanceOverride,
+    color_space: ColorSpace,
 }
 
 impl<'a, Message, Theme, Renderer> Button<'a, Message, Theme, Renderer>
@@ -64,21 +67,23 @@ where
             label: None,
             content: content.into(),
             on_press: None,
-            width: Length::Shrink,
-            height: Length::Shrink,
+            width: LengthType::Static(Length::Shrink),
+            height: LengthType::Static(Length::Shrink),
             padding: Padding::new(5.0),
             style: StyleType::Static(<Theme as StyleSheet>::Style::default()),
+            appearance: AppearanceOverride::default(),
+            color_space: ColorSpace::default(),
         }
     }
 
     /// Sets the width of the [`Button`].
-    pub fn width(mut self, width: impl Into<Length>) -> Self {
+    pub fn width(mut self, width: impl Into<LengthType>) -> Self {
         self.width = width.into();
         self
     }
 
     /// Sets the height of the [`Button`].
-    pub fn height(mut self, height: impl Into<Length>) -> Self {
+    pub fn height(mut self, height: impl Into<LengthType>) -> Self {
         self.height = height.into();
         self
     }
@@ -114,6 +119,19 @@ where
         self
     }
 
+    /// Replace values of the style's appearance with the ones that are set.
+    /// Used by keyframes to animate colors and borders without a stylesheet.
+    pub fn override_appearance(mut self, appearance: AppearanceOverride) -> Self {
+        self.appearance = appearance;
+        self
+    }
+
+    /// Sets the [`ColorSpace`] colors of the [`Button`] are blended in.
+    pub fn color_space(mut self, space: ColorSpace) -> Self {
+        self.color_space = space;
+        self
+    }
+
     /// Sets the [`Id`] of the [`Button`].
     pub fn id(mut self, id: Id) -> Self {
         self.id = id;
@@ -175,7 +193,7 @@ where
     }
 
     fn size(&self) -> cosmic::iced_core::Size<Length> {
-        cosmic::iced_core::Size::new(self.width, self.height)
+        cosmic::iced_core::Size::new(self.width.length(), self.height.length())
     }
 
     fn layout(
@@ -184,11 +202,27 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
+        let (width, height) = resolve_lengths(self.width, self.height, limits, |width, height| {
+            layout(
+                renderer,
+                limits,
+                width,
+                height,
+                self.padding,
+                |renderer, limits| {
+                    self.content
+                        .as_widget()
+                        .layout(&mut tree.children[0], renderer, limits)
+                },
+            )
+            .size()
+        });
+
         layout(
             renderer,
             limits,
-            self.width,
-            self.height,
+            width,
+            height,
             self.padding,
             |renderer, limits| {
                 self.content
@@ -272,6 +306,8 @@ where
             self.on_press.is_some(),
             theme,
             &self.style,
+            &self.appearance,
+            self.color_space,
             || tree.state.downcast_ref::<State>(),
         );
 
@@ -525,6 +561,8 @@ pub fn draw<'a, Renderer: cosmic::iced_core::Renderer, Theme: StyleSheet>(
     is_enabled: bool,
     style_sheet: &dyn StyleSheet<Style = <Theme as StyleSheet>::Style>,
     style: &StyleType<<Theme as StyleSheet>::Style>,
+    appearance: &AppearanceOverride,
+    space: ColorSpace,
     state: impl FnOnce() -> &'a State,
 ) -> Appearance {
     let is_mouse_over = cursor_position.is_over(bounds);
@@ -569,9 +607,10 @@ pub fn draw<'a, Renderer: cosmic::iced_core::Renderer, Theme: StyleSheet>(
                 )
             };
 
-            blend_appearances(one, two, *percent)
+            blend_appearances(one, two, *percent, space)
         }
     };
+    let styling = override_appearance(appearance, styling);
 
     if styling.background.is_some() || styling.border_width > 0.0 {
         if styling.shadow_offset != Vector::default() {
@@ -670,7 +709,31 @@ impl operation::Focusable for State {
     }
 }
 
-fn blend_appearances(one: Appearance, mut two: Appearance, percent: f32) -> Appearance {
+fn override_appearance(values: &AppearanceOverride, mut appearance: Appearance) -> Appearance {
+    if let Some(background) = values.background {
+        appearance.background = Some(background);
+    }
+    if let Some(text_color) = values.text_color {
+        appearance.text_color = Some(text_color);
+    }
+    if let Some(border_color) = values.border_color {
+        appearance.border_color = border_color;
+    }
+    if let Some(border_width) = values.border_width {
+        appearance.border_width = border_width;
+    }
+    if let Some(border_radius) = values.border_radius {
+        appearance.border_radius = border_radius.into();
+    }
+    appearance
+}
+
+fn blend_appearances(
+    one: Appearance,
+    mut two: Appearance,
+    percent: f32,
+    space: ColorSpace,
+) -> Appearance {
     use crate::lerp;
 
     // shadow offet
@@ -679,86 +742,14 @@ fn blend_appearances(one: Appearance, mut two: Appearance, percent: f32) -> Appe
     let x2 = two.shadow_offset.x;
     let y2 = two.shadow_offset.y;
 
-    // background
-    let background_mix: Background = match (one.background, two.background) {
-        (Some(Background::Color(c1)), Some(Background::Color(c2))) => {
-            Background::from(Color::from(static_array_from_iter::<f32, 4>(
-                c1.into_linear()
-                    .iter()
-                    .zip(c2.into_linear().iter())
-                    .map(|(o, t)| lerp(*o, *t, percent)),
-            )))
-        }
-        (
-            Some(Background::Gradient(Gradient::Linear(l1))),
-            Some(Background::Gradient(Gradient::Linear(l2))),
-        ) => {
-            let angle = lerp(l1.angle.0, l2.angle.0, percent);
-            let stops = l1
-                .stops
-                .iter()
-                .zip(l2.stops.iter())
-                .map(|(o, t)| match (o, t) {
-                    (
-                        Some(ColorStop {
-                            offset: o1,
-                            color: c1,
-                        }),
-                        Some(ColorStop {
-                            color: c2,
-                            offset: o2,
-                        }),
-                    ) => Some(ColorStop {
-                        color: static_array_from_iter::<f32, 4>(
-                            c1.into_linear()
-                                .iter()
-                                .zip(c2.into_linear().iter())
-                                .map(|(o, t)| lerp(*o, *t, percent)),
-                        )
-                        .into(),
-                        offset: lerp(*o1, *o2, percent),
-                    }),
-                    (a, b) => *if percent < 0.5 { a } else { b },
-                });
-            Background::Gradient(
-                Linear {
-                    angle: Radians(angle),
-                    stops: static_array_from_iter(stops),
-                }
-                .into(),
-            )
-        }
-        _ => Background::from(Color::from([0.0, 0.0, 0.0, 0.0])),
-    };
-
     // boarder color
-    let border_color = static_array_from_iter::<f32, 4>(
-        one.border_color
-            .into_linear()
-            .iter()
-            .zip(two.border_color.into_linear().iter())
-            .map(|(o, t)| lerp(*o, *t, percent)),
-    );
+    let border_color = space.mix(one.border_color, two.border_color, percent);
 
     // text
-    let text = one
-        .text_color
-        .iter()
-        .zip(two.text_color.iter())
-        .map(|(c1, c2)| {
-            static_array_from_iter::<f32, 4>(
-                c1.into_linear()
-                    .iter()
-                    .zip(c2.into_linear().iter())
-                    .map(|(o, t)| lerp(*o, *t, percent)),
-            )
-        })
-        .next()
-        .or_else(|| {
-            one.text_color
-                .or(two.text_color)
-                .map(|c| static_array_from_iter::<f32, 4>(c.into_linear().into_iter()))
-        });
+    let text = match (one.text_color, two.text_color) {
+        (Some(c1), Some(c2)) => Some(space.mix(c1, c2, percent)),
+        (c1, c2) => c1.or(c2),
+    };
 
     let br1: [f32; 4] = one.border_radius.into();
     let br2: [f32; 4] = two.border_radius.into();
@@ -771,10 +762,10 @@ fn blend_appearances(one: Appearance, mut two: Appearance, percent: f32) -> Appe
     ];
 
     two.shadow_offset = Vector::new(lerp(x1, x2, percent), lerp(y1, y2, percent));
-    two.background = Some(background_mix);
+    two.background = blend_backgrounds(one.background, two.background, percent, space);
     two.border_radius = br.into();
     two.border_width = lerp(one.border_width, two.border_width, percent);
-    two.border_color = border_color.into();
-    two.text_color = text.map(|c| c.into());
+    two.border_color = border_color;
+    two.text_color = text;
     two
 }
@@ -2,11 +2,10 @@
 use cosmic::cosmic_theme::LayeredTheme;
 use cosmic::iced_core::alignment::{self, Alignment};
 use cosmic::iced_core::event::{self, Event};
-use cosmic::iced_core::gradient::{ColorStop, Linear};
+use cosmic::iced_core::layout;
+use cosmic::iced_core::mouse;
 use cosmic::iced_core::renderer;
 use cosmic::iced_core::widget::{Id, Operation, Tree};
-use cosmic::iced_core::{layout, Gradient};
-use cosmic::iced_core::{mouse, Radians};
 use cosmic::iced_core::{overlay, Border};
 use cosmic::iced_core::{
     Background, Clipboard, Color, Element, Layout, Length, Padding, Pixels, Point, Rectangle,
@@ -14,8 +13,10 @@ use cosmic::iced_core::{
 };
 use cosmic::iced_style;
 
-use crate::utils::static_array_from_iter;
-use crate::widget::StyleType;
+use crate::widget::{
+    blend_backgrounds, blend_shadows, resolve_lengths, AppearanceOverride, LengthType, StyleType,
+};
+use crate::ColorSpace;
 
 use cosmic::iced_renderer::core::widget::OperationOutputWrapper;
 pub use cosmic::iced_style::container::{Appearance, StyleSheet};
@@ -32,13 +33,15 @@ where
 {
     id: Option<Id>,
     padding: Padding,
-    width: Length,
-    height: Length,
+    width: LengthType,
+    height: LengthType,
     max_width: f32,
     max_height: f32,
     horizontal_alignment: alignment::Horizontal,
     vertical_alignment: alignment::Vertical,
     style: StyleType<<Theme as StyleSheet>::Style>,
+    appearance: AppearanceOverride,
+    color_space: ColorSpace,
     content: Element<'a, Message, Theme, Renderer>,
 }
 
@@ -56,13 +59,15 @@ where
         Container {
             id: None,
             padding: Padding::ZERO,
-            width: Length::Shrink,
-            height: Length::Shrink,
+            width: LengthType::Static(Length::Shrink),
+            height: LengthType::Static(Length::Shrink),
             max_width: f32::INFINITY,
             max_height: f32::INFINITY,
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Top,
             style: StyleType::Static(Default::default()),
+            appearance: AppearanceOverride::default(),
+            color_space: ColorSpace::default(),
             content: content.into(),
         }
     }
@@ -80,13 +85,13 @@ where
     }
 
     /// Sets the width of the [`Container`].
-    pub fn width(mut self, width: impl Into<Length>) -> Self {
+    pub fn width(mut self, width: impl Into<LengthType>) -> Self {
         self.width = width.into();
         self
     }
 
     /// Sets the height of the [`Container`].
-    pub fn height(mut self, height: impl Into<Length>) -> Self {
+    pub fn height(mut self, height: impl Into<LengthType>) -> Self {
         self.height = height.into();
         self
     }
@@ -143,6 +148,19 @@ where
         self.style = StyleType::Blend(style1, style2, percent);
         self
     }
+
+    /// Replace values of the style's appearance with the ones that are set.
+    /// Used by keyframes to animate colors and borders without a stylesheet.
+    pub fn override_appearance(mut self, appearance: AppearanceOverride) -> Self {
+        self.appearance = appearance;
+        self
+    }
+
+    /// Sets the [`ColorSpace`] colors of the [`Container`] are blended in.
+    pub fn color_space(mut self, space: ColorSpace) -> Self {
+        self.color_space = space;
+        self
+    }
 }
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -161,7 +179,7 @@ where
     }
 
     fn size(&self) -> cosmic::iced_core::Size<Length> {
-        cosmic::iced_core::Size::new(self.width, self.height)
+        cosmic::iced_core::Size::new(self.width.length(), self.height.length())
     }
 
     fn layout(
@@ -170,11 +188,31 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
+        let (width, height) = resolve_lengths(self.width, self.height, limits, |width, height| {
+            layout(
+                renderer,
+                limits,
+                width,
+                height,
+                self.max_width,
+                self.max_height,
+                self.padding,
+                self.horizontal_alignment,
+                self.vertical_alignment,
+                |renderer, limits| {
+                    self.content
+                        .as_widget()
+                        .layout(&mut tree.children[0], renderer, limits)
+                },
+            )
+            .size()
+        });
+
         layout(
             renderer,
             limits,
-            self.width,
-            self.height,
+            width,
+            height,
             self.max_width,
             self.max_height,
             self.padding,
@@ -257,10 +295,14 @@ where
     ) {
         let style = match &self.style {
             StyleType::Static(style) => theme.appearance(style),
-            StyleType::Blend(one, two, percent) => {
-                blend_appearances(theme.appearance(one), theme.appearance(two), *percent)
-            }
+            StyleType::Blend(one, two, percent) => blend_appearances(
+                theme.appearance(one),
+                theme.appearance(two),
+                *percent,
+                self.color_space,
+            ),
         };
+        let style = self.appearance.container(style);
 
         draw_background(renderer, &style, layout.bounds());
 
@@ -388,86 +430,21 @@ fn blend_appearances(
     one: cosmic::iced_style::container::Appearance,
     mut two: cosmic::iced_style::container::Appearance,
     percent: f32,
+    space: ColorSpace,
 ) -> cosmic::iced_style::container::Appearance {
     use crate::lerp;
 
-    // background
-    let background_mix: Background = match (one.background, two.background) {
-        (Some(Background::Color(c1)), Some(Background::Color(c2))) => {
-            Background::from(Color::from(static_array_from_iter::<f32, 4>(
-                c1.into_linear()
-                    .iter()
-                    .zip(c2.into_linear().iter())
-                    .map(|(o, t)| lerp(*o, *t, percent)),
-            )))
-        }
-        (
-            Some(Background::Gradient(Gradient::Linear(l1))),
-            Some(Background::Gradient(Gradient::Linear(l2))),
-        ) => {
-            let angle = lerp(l1.angle.0, l2.angle.0, percent);
-            let stops = l1
-                .stops
-                .iter()
-                .zip(l2.stops.iter())
-                .map(|(o, t)| match (o, t) {
-                    (
-                        Some(ColorStop {
-                            offset: o1,
-                            color: c1,
-                        }),
-                        Some(ColorStop {
-                            color: c2,
-                            offset: o2,
-                        }),
-                    ) => Some(ColorStop {
-                        color: static_array_from_iter::<f32, 4>(
-                            c1.into_linear()
-                                .iter()
-                                .zip(c2.into_linear().iter())
-                                .map(|(o, t)| lerp(*o, *t, percent)),
-                        )
-                        .into(),
-                        offset: lerp(*o1, *o2, percent),
-                    }),
-                    (a, b) => *if percent < 0.5 { a } else { b },
-                });
-            Background::Gradient(
-                Linear {
-                    angle: Radians(angle),
-                    stops: static_array_from_iter(stops),
-                }
-                .into(),
-            )
-        }
-        _ => Background::from(Color::from([0.0, 0.0, 0.0, 0.0])),
-    };
     // boarder color
-    let border_color = static_array_from_iter::<f32, 4>(
-        one.border
-            .color
-            .into_linear()
-            .iter()
-            .zip(two.border.color.into_linear().iter())
-            .map(|(o, t)| lerp(*o, *t, percent)),
-    );
+    let border_color = space.mix(one.border.color, two.border.color, percent);
 
     // text
     let text = one
         .text_color
-        .map(|t| {
-            static_array_from_iter::<f32, 4>(
-                t.into_linear()
-                    .iter()
-                    .zip(two.text_color.unwrap_or(t).into_linear().iter())
-                    .map(|(o, t)| lerp(*o, *t, percent)),
-            )
-        })
-        .map(Into::<Color>::into);
+        .map(|t| space.mix(t, two.text_color.unwrap_or(t), percent));
 
     let one_border_radius: [f32; 4] = one.border.radius.into();
     let two_border_radius: [f32; 4] = two.border.radius.into();
-    two.background = Some(background_mix);
+    two.background = blend_backgrounds(one.background, two.background, percent, space);
     two.border.radius = [
         lerp(one_border_radius[0], two_border_radius[0], percent),
         lerp(one_border_radius[1], two_border_radius[1], percent),
@@ -476,7 +453,8 @@ fn blend_appearances(
     ]
     .into();
     two.border.width = lerp(one.border.width, two.border.width, percent);
-    two.border.color = border_color.into();
+    two.border.color = border_color;
+    two.shadow = blend_shadows(one.shadow, two.shadow, percent, space);
     two.text_color = text;
     two
 }
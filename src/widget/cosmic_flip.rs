@@ -0,0 +1,483 @@
+//! Rows and columns whose children glide to their new position when the layout changes.
+
+use std::collections::HashMap;
+
+use crate::keyframes::{transform, Transform};
+use crate::widget::{resolve_lengths, LengthType};
+use crate::{Chain, Duration, Ease, Quadratic, Repeat, Timeline};
+use cosmic::iced_core::event::{self, Event};
+use cosmic::iced_core::layout;
+use cosmic::iced_core::mouse;
+use cosmic::iced_core::overlay;
+use cosmic::iced_core::renderer;
+use cosmic::iced_core::widget::{self, tree, Operation, Tree};
+use cosmic::iced_core::{
+    Alignment, Clipboard, Element, Layout, Length, Padding, Pixels, Point, Rectangle, Shell, Size,
+    Vector, Widget,
+};
+use cosmic::iced_renderer::core::widget::OperationOutputWrapper;
+
+/// A row or column of keyed children that animates layout changes.
+///
+/// Every child has a key. When a child moves, because children were reordered,
+/// inserted or removed, it is drawn at its old position and glides to the new one.
+/// Children that keep their key also keep their widget state when reordered.
+///
+/// The glides are animations on the [`Timeline`], so they can be paused, sped up
+/// and seeked like any other. The [`Flip`] publishes the chains of the children
+/// that moved with the message from `on_move`: add them to the timeline in
+/// `update()`, and start it. Each chain's Id is the key of its child, so use keys
+/// that no other animation uses.
+#[allow(missing_debug_implementations)]
+pub struct Flip<'a, Message, Theme, Renderer = cosmic::iced::Renderer>
+where
+    Renderer: cosmic::iced_core::Renderer,
+{
+    direction: Direction,
+    spacing: f32,
+    padding: Padding,
+    width: LengthType,
+    height: LengthType,
+    align_items: Alignment,
+    duration: Duration,
+    ease: Ease,
+    timeline: Option<&'a Timeline>,
+    on_move: Option<Box<dyn Fn(Vec<Chain>) -> Message + 'a>>,
+    keys: Vec<widget::Id>,
+    children: Vec<Element<'a, Message, Theme, Renderer>>,
+}
+
+impl<'a, Message, Theme, Renderer> Flip<'a, Message, Theme, Renderer>
+where
+    Renderer: cosmic::iced_core::Renderer,
+{
+    fn new(direction: Direction) -> Self {
+        Flip {
+            direction,
+            spacing: 0.,
+            padding: Padding::ZERO,
+            width: LengthType::Static(Length::Shrink),
+            height: LengthType::Static(Length::Shrink),
+            align_items: Alignment::Start,
+            duration: Duration::from_millis(250),
+            ease: Quadratic::Out.into(),
+            timeline: None,
+            on_move: None,
+            keys: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Creates an empty [`Flip`] that lays out its children horizontally.
+    #[must_use]
+    pub fn row() -> Self {
+        Self::new(Direction::Row)
+    }
+
+    /// Creates an empty [`Flip`] that lays out its children vertically.
+    #[must_use]
+    pub fn column() -> Self {
+        Self::new(Direction::Column)
+    }
+
+    /// Adds a child, with a key that is unique in this [`Flip`].
+    #[must_use]
+    pub fn push(
+        mut self,
+        key: impl Into<widget::Id>,
+        child: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.keys.push(key.into());
+        self.children.push(child.into());
+        self
+    }
+
+    /// Sets the spacing between children.
+    #[must_use]
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the padding around the children.
+    #[must_use]
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the width of the [`Flip`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<LengthType>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Flip`].
+    #[must_use]
+    pub fn height(mut self, height: impl Into<LengthType>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets how children are aligned across the axis.
+    #[must_use]
+    pub fn align_items(mut self, align: Alignment) -> Self {
+        self.align_items = align;
+        self
+    }
+
+    /// Sets how long a child takes to reach its new position.
+    #[must_use]
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Sets the ease children move with.
+    #[must_use]
+    pub fn ease<E: Into<Ease>>(mut self, ease: E) -> Self {
+        self.ease = ease.into();
+        self
+    }
+
+    /// Sets the [`Timeline`] the children are moved by.
+    #[must_use]
+    pub fn timeline(mut self, timeline: &'a Timeline) -> Self {
+        self.timeline = Some(timeline);
+        self
+    }
+
+    /// Sets the message that carries the chains of the children that moved.
+    /// Without it, children jump straight to their new position.
+    #[must_use]
+    pub fn on_move(mut self, on_move: impl Fn(Vec<Chain>) -> Message + 'a) -> Self {
+        self.on_move = Some(Box::new(on_move));
+        self
+    }
+
+    // How far the child with `key` is drawn from its position in the layout.
+    fn offset(&self, state: &State, key: &widget::Id) -> Vector {
+        state
+            .starts
+            .get(key)
+            .copied()
+            .or_else(|| {
+                let timeline = self.timeline?;
+                Some(Vector::new(
+                    timeline.get(key, Transform::X)?.value,
+                    timeline.get(key, Transform::Y)?.value,
+                ))
+            })
+            .unwrap_or(Vector::new(0., 0.))
+    }
+
+    // The glide of the child with `key`, from `from` back to its position in the layout.
+    fn chain(&self, key: &widget::Id, from: Vector) -> Chain {
+        Chain::new(
+            key.clone(),
+            Repeat::Never,
+            vec![
+                transform(Duration::ZERO).translate(from).into(),
+                transform(self.duration)
+                    .translate([0., 0.])
+                    .ease(self.ease)
+                    .into(),
+            ],
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Direction {
+    Row,
+    Column,
+}
+
+impl Direction {
+    fn axis(self) -> layout::flex::Axis {
+        match self {
+            Direction::Row => layout::flex::Axis::Horizontal,
+            Direction::Column => layout::flex::Axis::Vertical,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    // The keys of the children in the tree, in order.
+    keys: Vec<widget::Id>,
+    // The position of every child, relative to the Flip, at the last layout.
+    positions: HashMap<widget::Id, Point>,
+    // The offsets children that just moved start their glide from. They are
+    // drawn there until their chains are on the timeline.
+    starts: HashMap<widget::Id, Vector>,
+    // Have the chains of `starts` been published?
+    published: bool,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Flip<'a, Message, Theme, Renderer>
+where
+    Renderer: cosmic::iced_core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            keys: self.keys.clone(),
+            ..State::default()
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        // Match children by key, not by index, so reordered children keep their state.
+        let state = tree.state.downcast_mut::<State>();
+        // The view is rebuilt after the published chains were added to the timeline.
+        if state.published {
+            state.starts.clear();
+            state.published = false;
+        }
+        let mut previous: HashMap<widget::Id, Tree> =
+            state.keys.drain(..).zip(tree.children.drain(..)).collect();
+        state.keys.clone_from(&self.keys);
+        tree.children = self
+            .keys
+            .iter()
+            .zip(&mut self.children)
+            .map(|(key, child)| match previous.remove(key) {
+                Some(mut child_tree) => {
+                    child_tree.diff(child.as_widget_mut());
+                    child_tree
+                }
+                None => Tree::new(child.as_widget()),
+            })
+            .collect();
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width.length(), self.height.length())
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let (width, height) = resolve_lengths(self.width, self.height, limits, |width, height| {
+            layout::flex::resolve(
+                self.direction.axis(),
+                renderer,
+                limits,
+                width,
+                height,
+                self.padding,
+                self.spacing,
+                self.align_items,
+                &self.children,
+                &mut tree.children,
+            )
+            .size()
+        });
+        let node = layout::flex::resolve(
+            self.direction.axis(),
+            renderer,
+            limits,
+            width,
+            height,
+            self.padding,
+            self.spacing,
+            self.align_items,
+            &self.children,
+            &mut tree.children,
+        );
+
+        let state = tree.state.downcast_mut::<State>();
+        for (key, child) in self.keys.iter().zip(node.children()) {
+            let position = child.bounds().position();
+            let Some(previous) = state.positions.insert(key.clone(), position) else {
+                continue;
+            };
+            if previous != position && self.on_move.is_some() {
+                // Start from wherever the child is drawn now, even mid motion.
+                let current = self.offset(state, key);
+                let _ = state
+                    .starts
+                    .insert(key.clone(), previous - position + current);
+                state.published = false;
+            }
+        }
+        state.positions.retain(|key, _| self.keys.contains(key));
+        state.starts.retain(|key, _| self.keys.contains(key));
+
+        node
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.children
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, state), layout)| {
+                    child
+                        .as_widget()
+                        .operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        // Layout can not publish messages, so the children that moved there are
+        // published with the next event. A redraw follows every layout.
+        let state = tree.state.downcast_mut::<State>();
+        if let Some(on_move) = self.on_move.as_ref().filter(|_| !state.published) {
+            if !state.starts.is_empty() {
+                let chains = state
+                    .starts
+                    .iter()
+                    .map(|(key, from)| self.chain(key, *from))
+                    .collect();
+                shell.publish(on_move(chains));
+                state.published = true;
+            }
+        }
+
+        self.children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    cursor_position,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child.as_widget().mouse_interaction(
+                    state,
+                    layout,
+                    cursor_position,
+                    viewport,
+                    renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        renderer_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        for (((key, child), child_tree), layout) in self
+            .keys
+            .iter()
+            .zip(&self.children)
+            .zip(&tree.children)
+            .zip(layout.children())
+        {
+            let offset = self.offset(state, key);
+            renderer.with_translation(offset, |renderer| {
+                child.as_widget().draw(
+                    child_tree,
+                    renderer,
+                    theme,
+                    renderer_style,
+                    layout,
+                    cursor_position,
+                    viewport,
+                );
+            });
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        overlay::from_children(&mut self.children, tree, layout, renderer)
+    }
+
+    #[cfg(feature = "a11y")]
+    /// get the a11y nodes for the widget
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        state: &Tree,
+        p: Point,
+    ) -> iced_accessibility::A11yTree {
+        iced_accessibility::A11yTree::join(
+            self.children
+                .iter()
+                .zip(layout.children())
+                .zip(state.children.iter())
+                .map(|((child, layout), state)| child.as_widget().a11y_nodes(layout, state, p)),
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Flip<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: 'a + cosmic::iced_core::Renderer,
+{
+    fn from(flip: Flip<'a, Message, Theme, Renderer>) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(flip)
+    }
+}
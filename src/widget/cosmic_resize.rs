@@ -0,0 +1,222 @@
+//! Resolve animated lengths against the space available at layout time.
+
+use cosmic::iced_core::event::{self, Event};
+use cosmic::iced_core::layout;
+use cosmic::iced_core::mouse;
+use cosmic::iced_core::overlay;
+use cosmic::iced_core::renderer;
+use cosmic::iced_core::widget::{Operation, Tree};
+use cosmic::iced_core::{Clipboard, Element, Layout, Length, Rectangle, Shell, Size, Widget};
+use cosmic::iced_renderer::core::widget::OperationOutputWrapper;
+
+use crate::widget::LengthType;
+
+/// A widget that sizes its content with [`LengthType`]s.
+///
+/// Used by keyframes to animate to and from lengths that are not `Fixed`,
+/// like `Length::Fill`, `Length::FillPortion` and `Length::Shrink`.
+#[allow(missing_debug_implementations)]
+pub struct Resize<'a, Message, Theme, Renderer = cosmic::iced::Renderer>
+where
+    Renderer: cosmic::iced_core::Renderer,
+{
+    width: LengthType,
+    height: LengthType,
+    content: Element<'a, Message, Theme, Renderer>,
+}
+
+impl<'a, Message, Theme, Renderer> Resize<'a, Message, Theme, Renderer>
+where
+    Renderer: cosmic::iced_core::Renderer,
+{
+    /// Creates a new [`Resize`], that is as large as its content.
+    pub fn new<T>(content: T) -> Self
+    where
+        T: Into<Element<'a, Message, Theme, Renderer>>,
+    {
+        Resize {
+            width: LengthType::Static(Length::Shrink),
+            height: LengthType::Static(Length::Shrink),
+            content: content.into(),
+        }
+    }
+
+    /// Sets the width of the [`Resize`].
+    pub fn width(mut self, width: impl Into<LengthType>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Resize`].
+    pub fn height(mut self, height: impl Into<LengthType>) -> Self {
+        self.height = height.into();
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Resize<'a, Message, Theme, Renderer>
+where
+    Renderer: cosmic::iced_core::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_mut(&mut self.content))
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width.length(), self.height.length())
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let (min, max) = (limits.min(), limits.max());
+        let shrink = if self.width.is_shrink() || self.height.is_shrink() {
+            self.content
+                .as_widget()
+                .layout(&mut tree.children[0], renderer, &limits.loose())
+                .size()
+        } else {
+            Size::ZERO
+        };
+
+        let size = Size::new(
+            self.width
+                .resolve(max.width, shrink.width)
+                .min(max.width)
+                .max(min.width),
+            self.height
+                .resolve(max.height, shrink.height)
+                .min(max.height)
+                .max(min.height),
+        );
+        let content = self.content.as_widget().layout(
+            &mut tree.children[0],
+            renderer,
+            &layout::Limits::new(Size::ZERO, size),
+        );
+
+        layout::Node::with_children(size, vec![content])
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<OperationOutputWrapper<Message>>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout.children().next().unwrap(),
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout.children().next().unwrap(),
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        renderer_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            renderer_style,
+            layout.children().next().unwrap(),
+            cursor_position,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+        )
+    }
+
+    #[cfg(feature = "a11y")]
+    /// get the a11y nodes for the widget
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        state: &Tree,
+        p: cosmic::iced_core::Point,
+    ) -> iced_accessibility::A11yTree {
+        let c_layout = layout.children().next().unwrap();
+        let c_state = &state.children[0];
+        self.content.as_widget().a11y_nodes(c_layout, c_state, p)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Resize<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: 'a + cosmic::iced_core::Renderer,
+{
+    fn from(resize: Resize<'a, Message, Theme, Renderer>) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(resize)
+    }
+}
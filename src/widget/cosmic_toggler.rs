@@ -10,6 +10,7 @@ use iced_core::{
 use crate::{
     chain, id, lerp,
     reexports::{iced, iced_core, iced_style, iced_widget},
+    ColorSpace,
 };
 pub use cosmic::iced_style::toggler::{Appearance, StyleSheet};
 
@@ -34,6 +35,7 @@ where
     font: Option<Renderer::Font>,
     style: <Theme as StyleSheet>::Style,
     percent: f32,
+    color_space: ColorSpace,
     anim_multiplier: f32,
 }
 
@@ -72,6 +74,7 @@ where
             font: None,
             style: Default::default(),
             percent: if is_toggled { 1.0 } else { 0.0 },
+            color_space: ColorSpace::default(),
             anim_multiplier: 1.0,
         }
     }
@@ -139,6 +142,12 @@ where
         self.percent = percent;
         self
     }
+
+    /// Sets the [`ColorSpace`] colors of the [`Toggler`] are blended in.
+    pub fn color_space(mut self, space: ColorSpace) -> Self {
+        self.color_space = space;
+        self
+    }
 }
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -296,12 +305,14 @@ where
                 theme.hovered(&self.style, false),
                 theme.hovered(&self.style, true),
                 self.percent,
+                self.color_space,
             )
         } else {
             blend_appearances(
                 theme.active(&self.style, false),
                 theme.active(&self.style, true),
                 self.percent,
+                self.color_space,
             )
         };
 
@@ -369,23 +380,18 @@ where
     }
 }
 
-fn blend_appearances(first: Appearance, mut other: Appearance, percent: f32) -> Appearance {
+fn blend_appearances(
+    first: Appearance,
+    mut other: Appearance,
+    percent: f32,
+    space: ColorSpace,
+) -> Appearance {
     if percent == 0. {
         first
     } else if percent == 1. {
         other
     } else {
-        let first_background = first.background.into_linear();
-
-        let other_background = std::mem::take(&mut other.background).into_linear();
-
-        other.background = crate::utils::static_array_from_iter::<f32, 4>(
-            first_background
-                .iter()
-                .zip(other_background.iter())
-                .map(|(o, t)| o * (1.0 - percent) + t * percent),
-        )
-        .into();
+        other.background = space.mix(first.background, other.background, percent);
 
         other
     }
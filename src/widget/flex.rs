@@ -0,0 +1,131 @@
+//! Rows and columns with animated lengths.
+
+use crate::reexports::iced_core;
+use crate::reexports::iced_widget;
+use iced_core::{Alignment, Element, Length, Padding, Pixels};
+
+use crate::widget::{LengthType, Resize};
+
+macro_rules! flex {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        ///
+        #[doc = concat!("It builds like an iced `", stringify!($name), "`, and turns into an [`Element`].")]
+        /// Blends between lengths that are not `Fixed` are resolved at layout time,
+        /// by a [`Resize`] around it.
+        #[allow(missing_debug_implementations)]
+        pub struct $name<'a, Message, Theme, Renderer>
+        where
+            Renderer: iced_core::Renderer,
+        {
+            inner: iced_widget::$name<'a, Message, Theme, Renderer>,
+            width: LengthType,
+            height: LengthType,
+        }
+
+        impl<'a, Message, Theme, Renderer> $name<'a, Message, Theme, Renderer>
+        where
+            Renderer: iced_core::Renderer,
+        {
+            #[doc = concat!("Creates an empty [`", stringify!($name), "`].")]
+            #[must_use]
+            pub fn new() -> Self {
+                $name {
+                    inner: iced_widget::$name::new(),
+                    width: LengthType::Static(Length::Shrink),
+                    height: LengthType::Static(Length::Shrink),
+                }
+            }
+
+            /// Sets the spacing between children.
+            #[must_use]
+            pub fn spacing(mut self, amount: impl Into<Pixels>) -> Self {
+                self.inner = self.inner.spacing(amount);
+                self
+            }
+
+            /// Sets the padding around the children.
+            #[must_use]
+            pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+                self.inner = self.inner.padding(padding);
+                self
+            }
+
+            #[doc = concat!("Sets the width of the [`", stringify!($name), "`].")]
+            #[must_use]
+            pub fn width(mut self, width: impl Into<LengthType>) -> Self {
+                self.width = width.into();
+                // Children pushed later may still grow a static length, like in iced.
+                if let LengthType::Static(width) = self.width {
+                    self.inner = self.inner.width(width);
+                }
+                self
+            }
+
+            #[doc = concat!("Sets the height of the [`", stringify!($name), "`].")]
+            #[must_use]
+            pub fn height(mut self, height: impl Into<LengthType>) -> Self {
+                self.height = height.into();
+                if let LengthType::Static(height) = self.height {
+                    self.inner = self.inner.height(height);
+                }
+                self
+            }
+
+            /// Sets how children are aligned across the axis.
+            #[must_use]
+            pub fn align_items(mut self, align: Alignment) -> Self {
+                self.inner = self.inner.align_items(align);
+                self
+            }
+
+            /// Sets whether children are clipped when they overflow.
+            #[must_use]
+            pub fn clip(mut self, clip: bool) -> Self {
+                self.inner = self.inner.clip(clip);
+                self
+            }
+
+            /// Adds a child.
+            #[must_use]
+            pub fn push(mut self, child: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+                self.inner = self.inner.push(child);
+                self
+            }
+        }
+
+        impl<'a, Message, Theme, Renderer> Default for $name<'a, Message, Theme, Renderer>
+        where
+            Renderer: iced_core::Renderer,
+        {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl<'a, Message, Theme, Renderer> From<$name<'a, Message, Theme, Renderer>>
+            for Element<'a, Message, Theme, Renderer>
+        where
+            Message: 'a,
+            Theme: 'a,
+            Renderer: iced_core::Renderer + 'a,
+        {
+            fn from(flex: $name<'a, Message, Theme, Renderer>) -> Self {
+                match (flex.width, flex.height) {
+                    (LengthType::Static(_), LengthType::Static(_)) => flex.inner.into(),
+                    (width, height) => Resize::new(flex.inner).width(width).height(height).into(),
+                }
+            }
+        }
+    };
+}
+
+flex! {
+    /// A row whose width and height can blend between any kind of length.
+    Row
+}
+
+flex! {
+    /// A column whose width and height can blend between any kind of length.
+    Column
+}
@@ -1,7 +1,6 @@
 //! Show toggle controls using togglers.
 
 use crate::reexports::{iced_core, iced_style, iced_widget};
-use crate::utils::static_array_from_iter;
 use iced_core::alignment;
 use iced_core::event;
 use iced_core::layout;
@@ -12,11 +11,11 @@ use iced_core::text;
 use iced::Size;
 use iced_core::widget::{self, tree, Tree};
 use iced_core::{
-    border::Border, color, Clipboard, Color, Element, Event, Layout, Length, Pixels, Rectangle,
-    Shell, Widget,
+    border::Border, Clipboard, Color, Element, Event, Layout, Length, Pixels, Rectangle, Shell,
+    Widget,
 };
 
-use crate::{chain, id, lerp};
+use crate::{chain, id, lerp, ColorSpace};
 
 pub use iced_style::toggler::{Appearance, StyleSheet};
 
@@ -40,6 +39,7 @@ where
     font: Option<<Renderer as iced_core::text::Renderer>::Font>,
     style: <Theme as StyleSheet>::Style,
     percent: f32,
+    color_space: ColorSpace,
     anim_multiplier: f32,
 }
 
@@ -76,6 +76,7 @@ where
             font: None,
             style: Default::default(),
             percent: if is_toggled { 1.0 } else { 0.0 },
+            color_space: ColorSpace::default(),
             anim_multiplier: 1.0,
         }
     }
@@ -132,6 +133,12 @@ where
         self
     }
 
+    /// Sets the [`ColorSpace`] colors of the [`Toggler`] are blended in.
+    pub fn color_space(mut self, space: ColorSpace) -> Self {
+        self.color_space = space;
+        self
+    }
+
     /// The default animation time is 100ms, to speed up the toggle
     /// animation use a value less than 1.0, and to slow down the
     /// animation use a value greater than 1.0.
@@ -289,12 +296,14 @@ where
                 theme.hovered(&self.style, false),
                 theme.hovered(&self.style, true),
                 self.percent,
+                self.color_space,
             )
         } else {
             blend_appearances(
                 theme.active(&self.style, false),
                 theme.active(&self.style, true),
                 self.percent,
+                self.color_space,
             )
         };
 
@@ -366,53 +375,30 @@ fn blend_appearances(
     one: iced_style::toggler::Appearance,
     mut two: iced_style::toggler::Appearance,
     percent: f32,
+    space: ColorSpace,
 ) -> iced_style::toggler::Appearance {
     if percent == 0. {
         one
     } else if percent == 1. {
         two
     } else {
-        let background = static_array_from_iter::<f32, 4>(
-            one.background
-                .into_linear()
-                .iter()
-                .zip(two.background.into_linear().iter())
-                .map(|(o, t)| lerp(*o, *t, percent)),
-        );
+        let background = space.mix(one.background, two.background, percent);
 
         let border_one: Color = one.background_border_color;
         let border_two: Color = two.background_border_color;
 
-        let new_border = static_array_from_iter::<f32, 4>(
-            border_one
-                .into_linear()
-                .iter()
-                .zip(border_two.into_linear().iter())
-                .map(|(o, t)| lerp(*o, *t, percent)),
-        );
+        let new_border = space.mix(border_one, border_two, percent);
 
-        let foreground = static_array_from_iter::<f32, 4>(
-            one.foreground
-                .into_linear()
-                .iter()
-                .zip(two.foreground.into_linear().iter())
-                .map(|(o, t)| lerp(*o, *t, percent)),
-        );
+        let foreground = space.mix(one.foreground, two.foreground, percent);
 
         let f_border_one: Color = one.foreground_border_color;
         let f_border_two: Color = two.foreground_border_color;
-        let new_f_border = static_array_from_iter::<f32, 4>(
-            f_border_one
-                .into_linear()
-                .iter()
-                .zip(f_border_two.into_linear().iter())
-                .map(|(o, t)| lerp(*o, *t, percent)),
-        );
+        let new_f_border = space.mix(f_border_one, f_border_two, percent);
 
-        two.background = background.into();
-        two.background_border_color = new_border.into();
-        two.foreground = foreground.into();
-        two.foreground_border_color = new_f_border.into();
+        two.background = background;
+        two.background_border_color = new_border;
+        two.foreground = foreground;
+        two.foreground_border_color = new_f_border;
         two
     }
 }
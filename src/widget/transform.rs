@@ -0,0 +1,374 @@
+//! Move and scale content without changing the layout.
+
+use crate::reexports::iced_core;
+use iced::Vector;
+use iced_core::event::{self, Event};
+use iced_core::layout;
+use iced_core::mouse;
+use iced_core::overlay;
+use iced_core::renderer;
+use iced_core::widget::{Operation, Tree};
+use iced_core::{
+    Clipboard, Element, Layout, Length, Point, Rectangle, Shell, Size, Transformation, Widget,
+};
+
+/// A widget that translates and scales its content when drawn.
+///
+/// The content is laid out as usual, then moved by the translation and scaled
+/// around its center. Because the layout is not touched, animating a
+/// [`Transform`] does not cause the rest of the tree to be resized.
+///
+/// There is no rotation. In this version of iced a renderer's transformation
+/// can only translate and scale, so content can not be drawn rotated.
+#[allow(missing_debug_implementations)]
+pub struct Transform<'a, Message, Theme, Renderer>
+where
+    Renderer: iced_core::Renderer,
+{
+    translation: Vector,
+    scale: f32,
+    content: Element<'a, Message, Theme, Renderer>,
+}
+
+impl<'a, Message, Theme, Renderer> Transform<'a, Message, Theme, Renderer>
+where
+    Renderer: iced_core::Renderer,
+{
+    /// Creates a new [`Transform`] that leaves its content untouched.
+    pub fn new<T>(content: T) -> Self
+    where
+        T: Into<Element<'a, Message, Theme, Renderer>>,
+    {
+        Transform {
+            translation: Vector::new(0., 0.),
+            scale: 1.0,
+            content: content.into(),
+        }
+    }
+
+    /// Sets how far the content is moved from its position in the layout.
+    pub fn translate(mut self, translation: impl Into<Vector>) -> Self {
+        self.translation = translation.into();
+        self
+    }
+
+    /// Sets the scale of the content, around its center.
+    /// Negative scales are treated as 0.0.
+    pub fn scale(mut self, scale: f32) -> Self {
+        self.scale = scale.max(0.);
+        self
+    }
+
+    fn placement(&self, bounds: Rectangle) -> Placement {
+        Placement {
+            center: bounds.center(),
+            translation: self.translation,
+            scale: self.scale,
+        }
+    }
+}
+
+// Where the content is drawn: moved by `translation` and scaled around `center`.
+#[derive(Debug, Clone, Copy)]
+struct Placement {
+    center: Point,
+    translation: Vector,
+    scale: f32,
+}
+
+impl Placement {
+    fn transformation(self) -> Transformation {
+        let center = self.center;
+        Transformation::translate(center.x + self.translation.x, center.y + self.translation.y)
+            * Transformation::scale(self.scale)
+            * Transformation::translate(-center.x, -center.y)
+    }
+
+    /// Maps a point on screen back to where it would be without the transformation.
+    fn untransform(self, point: Point) -> Option<Point> {
+        if self.scale <= 0. {
+            return None;
+        }
+        let center = self.center;
+        Some(Point::new(
+            (point.x - center.x - self.translation.x) / self.scale + center.x,
+            (point.y - center.y - self.translation.y) / self.scale + center.y,
+        ))
+    }
+
+    fn cursor(self, cursor: mouse::Cursor) -> mouse::Cursor {
+        match cursor {
+            mouse::Cursor::Available(point) => self
+                .untransform(point)
+                .map_or(mouse::Cursor::Unavailable, mouse::Cursor::Available),
+            mouse::Cursor::Unavailable => mouse::Cursor::Unavailable,
+        }
+    }
+
+    fn viewport(self, viewport: &Rectangle) -> Rectangle {
+        let top_left = self.untransform(viewport.position());
+        let bottom_right = self.untransform(Point::new(
+            viewport.x + viewport.width,
+            viewport.y + viewport.height,
+        ));
+        match (top_left, bottom_right) {
+            (Some(a), Some(b)) => Rectangle::new(a, Size::new(b.x - a.x, b.y - a.y)),
+            _ => *viewport,
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Transform<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: 'a + iced_core::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content))
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let content = self
+            .content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits);
+        layout::Node::with_children(content.size(), vec![content])
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let placement = self.placement(layout.bounds());
+        let cursor_position = placement.cursor(cursor_position);
+        let viewport = placement.viewport(viewport);
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout.children().next().unwrap(),
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+            &viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let placement = self.placement(layout.bounds());
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout.children().next().unwrap(),
+            placement.cursor(cursor_position),
+            &placement.viewport(viewport),
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        renderer_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        if self.scale <= 0. {
+            return;
+        }
+
+        let placement = self.placement(layout.bounds());
+        renderer.with_transformation(placement.transformation(), |renderer| {
+            self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                renderer_style,
+                layout.children().next().unwrap(),
+                placement.cursor(cursor_position),
+                &placement.viewport(viewport),
+            );
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        // Overlays are laid out where the content would be, then drawn with the same
+        // transformation as the content, around its center on screen.
+        let placement = self.placement(layout.bounds() + translation);
+        self.content
+            .as_widget_mut()
+            .overlay(
+                &mut tree.children[0],
+                layout.children().next().unwrap(),
+                renderer,
+                translation,
+            )
+            .map(|content| overlay::Element::new(Box::new(Overlay { content, placement })))
+    }
+}
+
+// An overlay of the content, moved and scaled along with it.
+struct Overlay<'a, Message, Theme, Renderer> {
+    content: overlay::Element<'a, Message, Theme, Renderer>,
+    placement: Placement,
+}
+
+impl<'a, Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Overlay<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: 'a + iced_core::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        self.content.layout(renderer, bounds)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        if self.placement.scale <= 0. {
+            return;
+        }
+        renderer.with_transformation(self.placement.transformation(), |renderer| {
+            self.content.draw(
+                renderer,
+                theme,
+                style,
+                layout,
+                self.placement.cursor(cursor),
+            );
+        });
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.operate(layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        self.content.on_event(
+            event,
+            layout,
+            self.placement.cursor(cursor),
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.mouse_interaction(
+            layout,
+            self.placement.cursor(cursor),
+            &self.placement.viewport(viewport),
+            renderer,
+        )
+    }
+
+    fn is_over(&self, layout: Layout<'_>, renderer: &Renderer, cursor_position: Point) -> bool {
+        self.placement
+            .untransform(cursor_position)
+            .is_some_and(|point| self.content.is_over(layout, renderer, point))
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let placement = self.placement;
+        self.content
+            .overlay(layout, renderer)
+            .map(|content| overlay::Element::new(Box::new(Overlay { content, placement })))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Transform<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: 'a + iced_core::Renderer,
+{
+    fn from(
+        transform: Transform<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(transform)
+    }
+}